@@ -0,0 +1,4593 @@
+// Macro-heavy corpus for benches/parse_bench.rs. Modelled on large test files that compare
+// rendered output against nested json! values.
+
+use dep_0::module_0::Item0;
+use dep_1::module_1::Item1;
+use dep_2::module_2::Item2;
+use dep_3::module_3::Item3;
+use dep_4::module_4::Item4;
+use dep_5::module_5::Item5;
+use dep_6::module_6::Item6;
+use dep_7::module_7::Item7;
+use dep_8::module_8::Item8;
+use dep_9::module_9::Item9;
+use dep_10::module_10::Item10;
+use dep_11::module_11::Item11;
+use dep_12::module_12::Item12;
+use dep_13::module_13::Item13;
+use dep_14::module_14::Item14;
+use dep_15::module_15::Item15;
+use dep_16::module_16::Item16;
+use dep_17::module_17::Item17;
+use dep_18::module_18::Item18;
+use dep_19::module_19::Item19;
+use dep_20::module_20::Item20;
+use dep_21::module_21::Item21;
+use dep_22::module_22::Item22;
+use dep_23::module_23::Item23;
+use dep_24::module_24::Item24;
+use dep_25::module_25::Item25;
+use dep_26::module_26::Item26;
+use dep_27::module_27::Item27;
+use dep_28::module_28::Item28;
+use dep_29::module_29::Item29;
+use dep_30::module_30::Item30;
+use dep_31::module_31::Item31;
+use dep_32::module_32::Item32;
+use dep_33::module_33::Item33;
+use dep_34::module_34::Item34;
+use dep_35::module_35::Item35;
+use dep_36::module_36::Item36;
+use dep_37::module_37::Item37;
+use dep_38::module_38::Item38;
+use dep_39::module_39::Item39;
+use dep_40::module_40::Item40;
+use dep_41::module_41::Item41;
+use dep_42::module_42::Item42;
+use dep_43::module_43::Item43;
+use dep_44::module_44::Item44;
+use dep_45::module_45::Item45;
+use dep_46::module_46::Item46;
+use dep_47::module_47::Item47;
+use dep_48::module_48::Item48;
+use dep_49::module_49::Item49;
+use dep_50::module_50::Item50;
+use dep_51::module_51::Item51;
+use dep_52::module_52::Item52;
+use dep_53::module_53::Item53;
+use dep_54::module_54::Item54;
+use dep_55::module_55::Item55;
+use dep_56::module_56::Item56;
+use dep_57::module_57::Item57;
+use dep_58::module_58::Item58;
+use dep_59::module_59::Item59;
+use dep_60::module_60::Item60;
+use dep_61::module_61::Item61;
+use dep_62::module_62::Item62;
+use dep_63::module_63::Item63;
+use dep_64::module_64::Item64;
+use dep_65::module_65::Item65;
+use dep_66::module_66::Item66;
+use dep_67::module_67::Item67;
+use dep_68::module_68::Item68;
+use dep_69::module_69::Item69;
+use dep_70::module_70::Item70;
+use dep_71::module_71::Item71;
+use dep_72::module_72::Item72;
+use dep_73::module_73::Item73;
+use dep_74::module_74::Item74;
+use dep_75::module_75::Item75;
+use dep_76::module_76::Item76;
+use dep_77::module_77::Item77;
+use dep_78::module_78::Item78;
+use dep_79::module_79::Item79;
+use dep_80::module_80::Item80;
+use dep_81::module_81::Item81;
+use dep_82::module_82::Item82;
+use dep_83::module_83::Item83;
+use dep_84::module_84::Item84;
+use dep_85::module_85::Item85;
+use dep_86::module_86::Item86;
+use dep_87::module_87::Item87;
+use dep_88::module_88::Item88;
+use dep_89::module_89::Item89;
+use dep_90::module_90::Item90;
+use dep_91::module_91::Item91;
+use dep_92::module_92::Item92;
+use dep_93::module_93::Item93;
+use dep_94::module_94::Item94;
+use dep_95::module_95::Item95;
+use dep_96::module_96::Item96;
+use dep_97::module_97::Item97;
+use dep_98::module_98::Item98;
+use dep_99::module_99::Item99;
+use dep_100::module_100::Item100;
+use dep_101::module_101::Item101;
+use dep_102::module_102::Item102;
+use dep_103::module_103::Item103;
+use dep_104::module_104::Item104;
+use dep_105::module_105::Item105;
+use dep_106::module_106::Item106;
+use dep_107::module_107::Item107;
+use dep_108::module_108::Item108;
+use dep_109::module_109::Item109;
+use dep_110::module_110::Item110;
+use dep_111::module_111::Item111;
+use dep_112::module_112::Item112;
+use dep_113::module_113::Item113;
+use dep_114::module_114::Item114;
+use dep_115::module_115::Item115;
+use dep_116::module_116::Item116;
+use dep_117::module_117::Item117;
+use dep_118::module_118::Item118;
+use dep_119::module_119::Item119;
+use dep_120::module_120::Item120;
+use dep_121::module_121::Item121;
+use dep_122::module_122::Item122;
+use dep_123::module_123::Item123;
+use dep_124::module_124::Item124;
+use dep_125::module_125::Item125;
+use dep_126::module_126::Item126;
+use dep_127::module_127::Item127;
+use dep_128::module_128::Item128;
+use dep_129::module_129::Item129;
+use dep_130::module_130::Item130;
+use dep_131::module_131::Item131;
+use dep_132::module_132::Item132;
+use dep_133::module_133::Item133;
+use dep_134::module_134::Item134;
+use dep_135::module_135::Item135;
+use dep_136::module_136::Item136;
+use dep_137::module_137::Item137;
+use dep_138::module_138::Item138;
+use dep_139::module_139::Item139;
+use dep_140::module_140::Item140;
+use dep_141::module_141::Item141;
+use dep_142::module_142::Item142;
+use dep_143::module_143::Item143;
+use dep_144::module_144::Item144;
+use dep_145::module_145::Item145;
+use dep_146::module_146::Item146;
+use dep_147::module_147::Item147;
+use dep_148::module_148::Item148;
+use dep_149::module_149::Item149;
+
+#[test]
+fn render_0() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 0,
+            "name": names_0::lookup("0-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 1,
+            "name": names_1::lookup("0-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 2,
+            "name": names_2::lookup("0-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 3,
+            "name": names_0::lookup("0-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 4,
+            "name": names_1::lookup("0-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 5,
+            "name": names_2::lookup("0-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 6,
+            "name": names_0::lookup("0-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 7,
+            "name": names_1::lookup("0-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_0", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(0),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_0),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_1() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 8,
+            "name": names_0::lookup("1-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 9,
+            "name": names_1::lookup("1-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 10,
+            "name": names_2::lookup("1-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 11,
+            "name": names_0::lookup("1-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 12,
+            "name": names_1::lookup("1-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 13,
+            "name": names_2::lookup("1-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 14,
+            "name": names_0::lookup("1-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 15,
+            "name": names_1::lookup("1-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_1", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(1),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_1),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_2() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 16,
+            "name": names_0::lookup("2-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 17,
+            "name": names_1::lookup("2-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 18,
+            "name": names_2::lookup("2-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 19,
+            "name": names_0::lookup("2-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 20,
+            "name": names_1::lookup("2-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 21,
+            "name": names_2::lookup("2-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 22,
+            "name": names_0::lookup("2-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 23,
+            "name": names_1::lookup("2-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_2", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(2),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_2),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_3() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 24,
+            "name": names_0::lookup("3-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 25,
+            "name": names_1::lookup("3-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 26,
+            "name": names_2::lookup("3-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 27,
+            "name": names_0::lookup("3-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 28,
+            "name": names_1::lookup("3-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 29,
+            "name": names_2::lookup("3-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 30,
+            "name": names_0::lookup("3-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 31,
+            "name": names_1::lookup("3-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_3", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(3),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_3),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_4() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 32,
+            "name": names_0::lookup("4-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 33,
+            "name": names_1::lookup("4-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 34,
+            "name": names_2::lookup("4-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 35,
+            "name": names_0::lookup("4-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 36,
+            "name": names_1::lookup("4-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 37,
+            "name": names_2::lookup("4-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 38,
+            "name": names_0::lookup("4-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 39,
+            "name": names_1::lookup("4-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_4", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(4),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_4),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_5() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 40,
+            "name": names_0::lookup("5-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 41,
+            "name": names_1::lookup("5-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 42,
+            "name": names_2::lookup("5-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 43,
+            "name": names_0::lookup("5-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 44,
+            "name": names_1::lookup("5-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 45,
+            "name": names_2::lookup("5-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 46,
+            "name": names_0::lookup("5-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 47,
+            "name": names_1::lookup("5-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_5", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(5),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_5),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_6() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 48,
+            "name": names_0::lookup("6-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 49,
+            "name": names_1::lookup("6-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 50,
+            "name": names_2::lookup("6-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 51,
+            "name": names_0::lookup("6-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 52,
+            "name": names_1::lookup("6-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 53,
+            "name": names_2::lookup("6-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 54,
+            "name": names_0::lookup("6-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 55,
+            "name": names_1::lookup("6-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_6", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(6),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_6),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_7() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 56,
+            "name": names_0::lookup("7-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 57,
+            "name": names_1::lookup("7-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 58,
+            "name": names_2::lookup("7-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 59,
+            "name": names_0::lookup("7-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 60,
+            "name": names_1::lookup("7-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 61,
+            "name": names_2::lookup("7-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 62,
+            "name": names_0::lookup("7-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 63,
+            "name": names_1::lookup("7-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_7", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(7),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_7),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_8() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 64,
+            "name": names_0::lookup("8-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 65,
+            "name": names_1::lookup("8-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 66,
+            "name": names_2::lookup("8-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 67,
+            "name": names_0::lookup("8-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 68,
+            "name": names_1::lookup("8-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 69,
+            "name": names_2::lookup("8-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 70,
+            "name": names_0::lookup("8-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 71,
+            "name": names_1::lookup("8-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_8", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(8),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_8),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_9() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 72,
+            "name": names_0::lookup("9-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 73,
+            "name": names_1::lookup("9-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 74,
+            "name": names_2::lookup("9-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 75,
+            "name": names_0::lookup("9-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 76,
+            "name": names_1::lookup("9-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 77,
+            "name": names_2::lookup("9-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 78,
+            "name": names_0::lookup("9-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 79,
+            "name": names_1::lookup("9-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_9", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(9),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_9),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_10() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 80,
+            "name": names_0::lookup("10-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 81,
+            "name": names_1::lookup("10-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 82,
+            "name": names_2::lookup("10-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 83,
+            "name": names_0::lookup("10-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 84,
+            "name": names_1::lookup("10-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 85,
+            "name": names_2::lookup("10-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 86,
+            "name": names_0::lookup("10-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 87,
+            "name": names_1::lookup("10-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_10", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(10),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_10),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_11() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 88,
+            "name": names_0::lookup("11-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 89,
+            "name": names_1::lookup("11-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 90,
+            "name": names_2::lookup("11-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 91,
+            "name": names_0::lookup("11-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 92,
+            "name": names_1::lookup("11-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 93,
+            "name": names_2::lookup("11-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 94,
+            "name": names_0::lookup("11-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 95,
+            "name": names_1::lookup("11-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_11", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(11),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_11),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_12() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 96,
+            "name": names_0::lookup("12-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 97,
+            "name": names_1::lookup("12-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 98,
+            "name": names_2::lookup("12-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 99,
+            "name": names_0::lookup("12-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 100,
+            "name": names_1::lookup("12-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 101,
+            "name": names_2::lookup("12-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 102,
+            "name": names_0::lookup("12-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 103,
+            "name": names_1::lookup("12-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_12", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(12),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_12),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_13() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 104,
+            "name": names_0::lookup("13-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 105,
+            "name": names_1::lookup("13-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 106,
+            "name": names_2::lookup("13-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 107,
+            "name": names_0::lookup("13-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 108,
+            "name": names_1::lookup("13-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 109,
+            "name": names_2::lookup("13-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 110,
+            "name": names_0::lookup("13-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 111,
+            "name": names_1::lookup("13-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_13", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(13),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_13),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_14() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 112,
+            "name": names_0::lookup("14-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 113,
+            "name": names_1::lookup("14-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 114,
+            "name": names_2::lookup("14-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 115,
+            "name": names_0::lookup("14-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 116,
+            "name": names_1::lookup("14-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 117,
+            "name": names_2::lookup("14-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 118,
+            "name": names_0::lookup("14-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 119,
+            "name": names_1::lookup("14-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_14", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(14),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_14),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_15() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 120,
+            "name": names_0::lookup("15-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 121,
+            "name": names_1::lookup("15-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 122,
+            "name": names_2::lookup("15-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 123,
+            "name": names_0::lookup("15-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 124,
+            "name": names_1::lookup("15-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 125,
+            "name": names_2::lookup("15-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 126,
+            "name": names_0::lookup("15-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 127,
+            "name": names_1::lookup("15-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_15", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(15),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_15),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_16() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 128,
+            "name": names_0::lookup("16-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 129,
+            "name": names_1::lookup("16-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 130,
+            "name": names_2::lookup("16-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 131,
+            "name": names_0::lookup("16-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 132,
+            "name": names_1::lookup("16-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 133,
+            "name": names_2::lookup("16-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 134,
+            "name": names_0::lookup("16-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 135,
+            "name": names_1::lookup("16-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_16", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(16),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_16),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_17() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 136,
+            "name": names_0::lookup("17-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 137,
+            "name": names_1::lookup("17-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 138,
+            "name": names_2::lookup("17-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 139,
+            "name": names_0::lookup("17-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 140,
+            "name": names_1::lookup("17-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 141,
+            "name": names_2::lookup("17-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 142,
+            "name": names_0::lookup("17-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 143,
+            "name": names_1::lookup("17-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_17", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(17),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_17),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_18() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 144,
+            "name": names_0::lookup("18-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 145,
+            "name": names_1::lookup("18-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 146,
+            "name": names_2::lookup("18-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 147,
+            "name": names_0::lookup("18-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 148,
+            "name": names_1::lookup("18-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 149,
+            "name": names_2::lookup("18-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 150,
+            "name": names_0::lookup("18-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 151,
+            "name": names_1::lookup("18-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_18", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(18),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_18),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_19() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 152,
+            "name": names_0::lookup("19-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 153,
+            "name": names_1::lookup("19-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 154,
+            "name": names_2::lookup("19-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 155,
+            "name": names_0::lookup("19-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 156,
+            "name": names_1::lookup("19-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 157,
+            "name": names_2::lookup("19-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 158,
+            "name": names_0::lookup("19-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 159,
+            "name": names_1::lookup("19-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_19", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(19),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_19),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_20() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 160,
+            "name": names_0::lookup("20-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 161,
+            "name": names_1::lookup("20-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 162,
+            "name": names_2::lookup("20-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 163,
+            "name": names_0::lookup("20-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 164,
+            "name": names_1::lookup("20-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 165,
+            "name": names_2::lookup("20-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 166,
+            "name": names_0::lookup("20-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 167,
+            "name": names_1::lookup("20-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_20", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(20),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_20),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_21() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 168,
+            "name": names_0::lookup("21-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 169,
+            "name": names_1::lookup("21-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 170,
+            "name": names_2::lookup("21-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 171,
+            "name": names_0::lookup("21-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 172,
+            "name": names_1::lookup("21-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 173,
+            "name": names_2::lookup("21-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 174,
+            "name": names_0::lookup("21-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 175,
+            "name": names_1::lookup("21-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_21", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(21),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_21),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_22() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 176,
+            "name": names_0::lookup("22-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 177,
+            "name": names_1::lookup("22-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 178,
+            "name": names_2::lookup("22-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 179,
+            "name": names_0::lookup("22-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 180,
+            "name": names_1::lookup("22-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 181,
+            "name": names_2::lookup("22-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 182,
+            "name": names_0::lookup("22-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 183,
+            "name": names_1::lookup("22-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_22", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(22),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_22),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_23() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 184,
+            "name": names_0::lookup("23-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 185,
+            "name": names_1::lookup("23-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 186,
+            "name": names_2::lookup("23-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 187,
+            "name": names_0::lookup("23-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 188,
+            "name": names_1::lookup("23-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 189,
+            "name": names_2::lookup("23-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 190,
+            "name": names_0::lookup("23-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 191,
+            "name": names_1::lookup("23-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_23", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(23),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_23),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_24() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 192,
+            "name": names_0::lookup("24-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 193,
+            "name": names_1::lookup("24-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 194,
+            "name": names_2::lookup("24-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 195,
+            "name": names_0::lookup("24-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 196,
+            "name": names_1::lookup("24-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 197,
+            "name": names_2::lookup("24-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 198,
+            "name": names_0::lookup("24-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 199,
+            "name": names_1::lookup("24-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_24", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(24),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_24),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_25() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 200,
+            "name": names_0::lookup("25-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 201,
+            "name": names_1::lookup("25-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 202,
+            "name": names_2::lookup("25-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 203,
+            "name": names_0::lookup("25-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 204,
+            "name": names_1::lookup("25-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 205,
+            "name": names_2::lookup("25-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 206,
+            "name": names_0::lookup("25-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 207,
+            "name": names_1::lookup("25-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_25", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(25),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_25),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_26() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 208,
+            "name": names_0::lookup("26-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 209,
+            "name": names_1::lookup("26-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 210,
+            "name": names_2::lookup("26-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 211,
+            "name": names_0::lookup("26-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 212,
+            "name": names_1::lookup("26-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 213,
+            "name": names_2::lookup("26-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 214,
+            "name": names_0::lookup("26-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 215,
+            "name": names_1::lookup("26-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_26", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(26),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_26),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_27() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 216,
+            "name": names_0::lookup("27-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 217,
+            "name": names_1::lookup("27-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 218,
+            "name": names_2::lookup("27-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 219,
+            "name": names_0::lookup("27-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 220,
+            "name": names_1::lookup("27-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 221,
+            "name": names_2::lookup("27-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 222,
+            "name": names_0::lookup("27-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 223,
+            "name": names_1::lookup("27-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_27", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(27),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_27),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_28() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 224,
+            "name": names_0::lookup("28-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 225,
+            "name": names_1::lookup("28-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 226,
+            "name": names_2::lookup("28-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 227,
+            "name": names_0::lookup("28-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 228,
+            "name": names_1::lookup("28-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 229,
+            "name": names_2::lookup("28-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 230,
+            "name": names_0::lookup("28-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 231,
+            "name": names_1::lookup("28-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_28", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(28),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_28),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_29() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 232,
+            "name": names_0::lookup("29-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 233,
+            "name": names_1::lookup("29-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 234,
+            "name": names_2::lookup("29-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 235,
+            "name": names_0::lookup("29-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 236,
+            "name": names_1::lookup("29-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 237,
+            "name": names_2::lookup("29-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 238,
+            "name": names_0::lookup("29-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 239,
+            "name": names_1::lookup("29-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_29", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(29),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_29),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_30() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 240,
+            "name": names_0::lookup("30-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 241,
+            "name": names_1::lookup("30-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 242,
+            "name": names_2::lookup("30-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 243,
+            "name": names_0::lookup("30-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 244,
+            "name": names_1::lookup("30-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 245,
+            "name": names_2::lookup("30-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 246,
+            "name": names_0::lookup("30-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 247,
+            "name": names_1::lookup("30-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_30", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(30),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_30),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_31() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 248,
+            "name": names_0::lookup("31-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 249,
+            "name": names_1::lookup("31-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 250,
+            "name": names_2::lookup("31-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 251,
+            "name": names_0::lookup("31-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 252,
+            "name": names_1::lookup("31-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 253,
+            "name": names_2::lookup("31-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 254,
+            "name": names_0::lookup("31-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 255,
+            "name": names_1::lookup("31-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_31", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(31),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_31),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_32() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 256,
+            "name": names_0::lookup("32-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 257,
+            "name": names_1::lookup("32-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 258,
+            "name": names_2::lookup("32-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 259,
+            "name": names_0::lookup("32-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 260,
+            "name": names_1::lookup("32-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 261,
+            "name": names_2::lookup("32-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 262,
+            "name": names_0::lookup("32-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 263,
+            "name": names_1::lookup("32-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_32", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(32),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_32),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_33() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 264,
+            "name": names_0::lookup("33-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 265,
+            "name": names_1::lookup("33-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 266,
+            "name": names_2::lookup("33-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 267,
+            "name": names_0::lookup("33-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 268,
+            "name": names_1::lookup("33-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 269,
+            "name": names_2::lookup("33-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 270,
+            "name": names_0::lookup("33-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 271,
+            "name": names_1::lookup("33-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_33", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(33),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_33),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_34() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 272,
+            "name": names_0::lookup("34-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 273,
+            "name": names_1::lookup("34-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 274,
+            "name": names_2::lookup("34-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 275,
+            "name": names_0::lookup("34-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 276,
+            "name": names_1::lookup("34-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 277,
+            "name": names_2::lookup("34-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 278,
+            "name": names_0::lookup("34-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 279,
+            "name": names_1::lookup("34-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_34", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(34),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_34),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_35() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 280,
+            "name": names_0::lookup("35-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 281,
+            "name": names_1::lookup("35-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 282,
+            "name": names_2::lookup("35-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 283,
+            "name": names_0::lookup("35-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 284,
+            "name": names_1::lookup("35-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 285,
+            "name": names_2::lookup("35-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 286,
+            "name": names_0::lookup("35-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 287,
+            "name": names_1::lookup("35-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_35", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(35),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_35),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_36() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 288,
+            "name": names_0::lookup("36-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 289,
+            "name": names_1::lookup("36-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 290,
+            "name": names_2::lookup("36-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 291,
+            "name": names_0::lookup("36-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 292,
+            "name": names_1::lookup("36-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 293,
+            "name": names_2::lookup("36-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 294,
+            "name": names_0::lookup("36-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 295,
+            "name": names_1::lookup("36-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_36", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(36),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_36),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_37() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 296,
+            "name": names_0::lookup("37-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 297,
+            "name": names_1::lookup("37-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 298,
+            "name": names_2::lookup("37-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 299,
+            "name": names_0::lookup("37-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 300,
+            "name": names_1::lookup("37-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 301,
+            "name": names_2::lookup("37-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 302,
+            "name": names_0::lookup("37-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 303,
+            "name": names_1::lookup("37-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_37", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(37),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_37),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_38() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 304,
+            "name": names_0::lookup("38-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 305,
+            "name": names_1::lookup("38-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 306,
+            "name": names_2::lookup("38-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 307,
+            "name": names_0::lookup("38-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 308,
+            "name": names_1::lookup("38-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 309,
+            "name": names_2::lookup("38-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 310,
+            "name": names_0::lookup("38-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 311,
+            "name": names_1::lookup("38-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_38", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(38),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_38),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_39() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 312,
+            "name": names_0::lookup("39-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 313,
+            "name": names_1::lookup("39-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 314,
+            "name": names_2::lookup("39-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 315,
+            "name": names_0::lookup("39-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 316,
+            "name": names_1::lookup("39-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 317,
+            "name": names_2::lookup("39-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 318,
+            "name": names_0::lookup("39-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 319,
+            "name": names_1::lookup("39-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_39", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(39),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_39),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_40() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 320,
+            "name": names_0::lookup("40-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 321,
+            "name": names_1::lookup("40-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 322,
+            "name": names_2::lookup("40-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 323,
+            "name": names_0::lookup("40-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 324,
+            "name": names_1::lookup("40-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 325,
+            "name": names_2::lookup("40-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 326,
+            "name": names_0::lookup("40-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 327,
+            "name": names_1::lookup("40-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_40", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(40),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_40),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_41() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 328,
+            "name": names_0::lookup("41-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 329,
+            "name": names_1::lookup("41-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 330,
+            "name": names_2::lookup("41-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 331,
+            "name": names_0::lookup("41-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 332,
+            "name": names_1::lookup("41-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 333,
+            "name": names_2::lookup("41-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 334,
+            "name": names_0::lookup("41-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 335,
+            "name": names_1::lookup("41-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_41", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(41),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_41),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_42() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 336,
+            "name": names_0::lookup("42-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 337,
+            "name": names_1::lookup("42-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 338,
+            "name": names_2::lookup("42-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 339,
+            "name": names_0::lookup("42-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 340,
+            "name": names_1::lookup("42-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 341,
+            "name": names_2::lookup("42-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 342,
+            "name": names_0::lookup("42-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 343,
+            "name": names_1::lookup("42-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_42", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(42),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_42),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_43() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 344,
+            "name": names_0::lookup("43-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 345,
+            "name": names_1::lookup("43-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 346,
+            "name": names_2::lookup("43-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 347,
+            "name": names_0::lookup("43-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 348,
+            "name": names_1::lookup("43-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 349,
+            "name": names_2::lookup("43-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 350,
+            "name": names_0::lookup("43-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 351,
+            "name": names_1::lookup("43-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_43", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(43),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_43),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_44() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 352,
+            "name": names_0::lookup("44-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 353,
+            "name": names_1::lookup("44-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 354,
+            "name": names_2::lookup("44-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 355,
+            "name": names_0::lookup("44-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 356,
+            "name": names_1::lookup("44-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 357,
+            "name": names_2::lookup("44-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 358,
+            "name": names_0::lookup("44-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 359,
+            "name": names_1::lookup("44-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_44", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(44),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_44),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_45() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 360,
+            "name": names_0::lookup("45-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 361,
+            "name": names_1::lookup("45-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 362,
+            "name": names_2::lookup("45-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 363,
+            "name": names_0::lookup("45-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 364,
+            "name": names_1::lookup("45-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 365,
+            "name": names_2::lookup("45-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 366,
+            "name": names_0::lookup("45-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 367,
+            "name": names_1::lookup("45-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_45", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(45),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_45),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_46() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 368,
+            "name": names_0::lookup("46-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 369,
+            "name": names_1::lookup("46-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 370,
+            "name": names_2::lookup("46-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 371,
+            "name": names_0::lookup("46-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 372,
+            "name": names_1::lookup("46-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 373,
+            "name": names_2::lookup("46-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 374,
+            "name": names_0::lookup("46-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 375,
+            "name": names_1::lookup("46-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_46", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(46),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_46),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_47() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 376,
+            "name": names_0::lookup("47-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 377,
+            "name": names_1::lookup("47-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 378,
+            "name": names_2::lookup("47-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 379,
+            "name": names_0::lookup("47-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 380,
+            "name": names_1::lookup("47-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 381,
+            "name": names_2::lookup("47-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 382,
+            "name": names_0::lookup("47-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 383,
+            "name": names_1::lookup("47-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_47", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(47),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_47),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_48() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 384,
+            "name": names_0::lookup("48-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 385,
+            "name": names_1::lookup("48-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 386,
+            "name": names_2::lookup("48-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 387,
+            "name": names_0::lookup("48-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 388,
+            "name": names_1::lookup("48-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 389,
+            "name": names_2::lookup("48-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 390,
+            "name": names_0::lookup("48-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 391,
+            "name": names_1::lookup("48-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_48", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(48),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_48),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_49() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 392,
+            "name": names_0::lookup("49-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 393,
+            "name": names_1::lookup("49-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 394,
+            "name": names_2::lookup("49-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 395,
+            "name": names_0::lookup("49-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 396,
+            "name": names_1::lookup("49-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 397,
+            "name": names_2::lookup("49-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 398,
+            "name": names_0::lookup("49-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 399,
+            "name": names_1::lookup("49-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_49", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(49),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_49),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_50() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 400,
+            "name": names_0::lookup("50-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 401,
+            "name": names_1::lookup("50-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 402,
+            "name": names_2::lookup("50-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 403,
+            "name": names_0::lookup("50-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 404,
+            "name": names_1::lookup("50-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 405,
+            "name": names_2::lookup("50-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 406,
+            "name": names_0::lookup("50-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 407,
+            "name": names_1::lookup("50-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_50", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(50),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_50),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_51() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 408,
+            "name": names_0::lookup("51-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 409,
+            "name": names_1::lookup("51-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 410,
+            "name": names_2::lookup("51-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 411,
+            "name": names_0::lookup("51-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 412,
+            "name": names_1::lookup("51-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 413,
+            "name": names_2::lookup("51-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 414,
+            "name": names_0::lookup("51-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 415,
+            "name": names_1::lookup("51-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_51", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(51),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_51),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_52() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 416,
+            "name": names_0::lookup("52-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 417,
+            "name": names_1::lookup("52-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 418,
+            "name": names_2::lookup("52-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 419,
+            "name": names_0::lookup("52-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 420,
+            "name": names_1::lookup("52-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 421,
+            "name": names_2::lookup("52-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 422,
+            "name": names_0::lookup("52-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 423,
+            "name": names_1::lookup("52-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_52", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(52),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_52),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_53() {
+    let ctx = context_4::Context::from_value(json!({
+        "field_0": {
+            "id": 424,
+            "name": names_0::lookup("53-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 425,
+            "name": names_1::lookup("53-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 426,
+            "name": names_2::lookup("53-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 427,
+            "name": names_0::lookup("53-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 428,
+            "name": names_1::lookup("53-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 429,
+            "name": names_2::lookup("53-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 430,
+            "name": names_0::lookup("53-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 431,
+            "name": names_1::lookup("53-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_53", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(53),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_53),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_54() {
+    let ctx = context_5::Context::from_value(json!({
+        "field_0": {
+            "id": 432,
+            "name": names_0::lookup("54-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 433,
+            "name": names_1::lookup("54-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 434,
+            "name": names_2::lookup("54-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 435,
+            "name": names_0::lookup("54-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 436,
+            "name": names_1::lookup("54-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 437,
+            "name": names_2::lookup("54-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 438,
+            "name": names_0::lookup("54-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 439,
+            "name": names_1::lookup("54-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_54", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(54),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_54),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_55() {
+    let ctx = context_6::Context::from_value(json!({
+        "field_0": {
+            "id": 440,
+            "name": names_0::lookup("55-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 441,
+            "name": names_1::lookup("55-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 442,
+            "name": names_2::lookup("55-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 443,
+            "name": names_0::lookup("55-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 444,
+            "name": names_1::lookup("55-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 445,
+            "name": names_2::lookup("55-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 446,
+            "name": names_0::lookup("55-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 447,
+            "name": names_1::lookup("55-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_0::render("template_55", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(55),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_55),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_56() {
+    let ctx = context_0::Context::from_value(json!({
+        "field_0": {
+            "id": 448,
+            "name": names_0::lookup("56-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 449,
+            "name": names_1::lookup("56-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 450,
+            "name": names_2::lookup("56-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 451,
+            "name": names_0::lookup("56-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 452,
+            "name": names_1::lookup("56-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 453,
+            "name": names_2::lookup("56-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 454,
+            "name": names_0::lookup("56-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 455,
+            "name": names_1::lookup("56-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_1::render("template_56", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(56),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_0.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_56),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_57() {
+    let ctx = context_1::Context::from_value(json!({
+        "field_0": {
+            "id": 456,
+            "name": names_0::lookup("57-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 457,
+            "name": names_1::lookup("57-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 458,
+            "name": names_2::lookup("57-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 459,
+            "name": names_0::lookup("57-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 460,
+            "name": names_1::lookup("57-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 461,
+            "name": names_2::lookup("57-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 462,
+            "name": names_0::lookup("57-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 463,
+            "name": names_1::lookup("57-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_2::render("template_57", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(57),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_1.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_57),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_58() {
+    let ctx = context_2::Context::from_value(json!({
+        "field_0": {
+            "id": 464,
+            "name": names_0::lookup("58-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 465,
+            "name": names_1::lookup("58-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 466,
+            "name": names_2::lookup("58-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 467,
+            "name": names_0::lookup("58-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 468,
+            "name": names_1::lookup("58-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 469,
+            "name": names_2::lookup("58-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 470,
+            "name": names_0::lookup("58-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 471,
+            "name": names_1::lookup("58-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_3::render("template_58", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(58),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_2.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_58),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
+
+#[test]
+fn render_59() {
+    let ctx = context_3::Context::from_value(json!({
+        "field_0": {
+            "id": 472,
+            "name": names_0::lookup("59-0"),
+            "tags": ["a", "b", tags::tag(0)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_1": {
+            "id": 473,
+            "name": names_1::lookup("59-1"),
+            "tags": ["a", "b", tags::tag(1)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_2": {
+            "id": 474,
+            "name": names_2::lookup("59-2"),
+            "tags": ["a", "b", tags::tag(2)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_3": {
+            "id": 475,
+            "name": names_0::lookup("59-3"),
+            "tags": ["a", "b", tags::tag(3)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_4": {
+            "id": 476,
+            "name": names_1::lookup("59-4"),
+            "tags": ["a", "b", tags::tag(4)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_5": {
+            "id": 477,
+            "name": names_2::lookup("59-5"),
+            "tags": ["a", "b", tags::tag(5)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_6": {
+            "id": 478,
+            "name": names_0::lookup("59-6"),
+            "tags": ["a", "b", tags::tag(6)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+        "field_7": {
+            "id": 479,
+            "name": names_1::lookup("59-7"),
+            "tags": ["a", "b", tags::tag(7)],
+            "nested": { "deep": { "deeper": [1, 2, 3, { "x": null }] } },
+        },
+    }))
+    .unwrap();
+    assert_eq!(
+        renderer_4::render("template_59", &ctx).unwrap(),
+        format!(
+            "{} {} {}",
+            fmt_dep::header(59),
+            vec![1, 2, 3].iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            include_str!("expected_3.txt"),
+        ),
+        "mismatch in {}",
+        stringify!(render_59),
+    );
+    let page = html! {
+        <ul class="list">
+            <li>{ html_dep::item(0) }</li>
+            <li>{ html_dep::item(1) }</li>
+            <li>{ html_dep::item(2) }</li>
+            <li>{ html_dep::item(3) }</li>
+        </ul>
+    };
+}
@@ -0,0 +1,66 @@
+//! Times parsing of the files in `bench_data`. Run with `cargo bench` or
+//! `bazel run //gazelle_rust_parser/benches:parse_bench`. Recorded results and how to reproduce
+//! them are in `README.md`.
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+const WARMUP_ITERATIONS: u32 = 3;
+const ITERATIONS: u32 = 20;
+
+fn bench_file(dir: &Path, filename: &str) -> Result<(), Box<dyn Error>> {
+    let contents = std::fs::read_to_string(dir.join(filename))?;
+    let path = PathBuf::from(filename);
+    let options = gazelle_rust_parser::ParseOptions::default();
+
+    for _ in 0..WARMUP_ITERATIONS {
+        gazelle_rust_parser::parse_imports_from_str(&contents, &options, path.clone())?;
+    }
+
+    let mut total = Duration::ZERO;
+    let mut fastest = Duration::MAX;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let rust_imports =
+            gazelle_rust_parser::parse_imports_from_str(&contents, &options, path.clone())?;
+        let elapsed = start.elapsed();
+        std::hint::black_box(rust_imports);
+
+        total += elapsed;
+        fastest = fastest.min(elapsed);
+    }
+
+    println!(
+        "{filename}: mean {:?}, fastest {:?} ({ITERATIONS} iterations)",
+        total / ITERATIONS,
+        fastest,
+    );
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    #[cfg(feature = "bazel")]
+    let dir = {
+        let r = runfiles::Runfiles::create().unwrap();
+        r.rlocation("_main/gazelle_rust_parser/bench_data/")
+            .unwrap()
+    };
+    #[cfg(not(feature = "bazel"))]
+    let dir = {
+        let mut d = PathBuf::from(std::env!("CARGO_MANIFEST_DIR"));
+        d.push("bench_data");
+        d
+    };
+
+    let mut filenames: Vec<_> = std::fs::read_dir(&dir)?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<_, _>>()?;
+    filenames.retain(|filename| filename.ends_with(".rs"));
+    filenames.sort();
+
+    for filename in filenames {
+        bench_file(&dir, &filename)?;
+    }
+    Ok(())
+}
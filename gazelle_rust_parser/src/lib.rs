@@ -1,6 +1,6 @@
 #![deny(unused_must_use)]
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::error::Error;
 use std::fs::File;
 use std::io::Read;
@@ -8,6 +8,7 @@ use std::path::{Path, PathBuf};
 
 use proc_macro2::{TokenStream, TokenTree};
 use syn::ext::IdentExt;
+use syn::parse::Parser;
 use syn::parse_file;
 use syn::punctuated::Punctuated;
 use syn::visit::{self, Visit};
@@ -18,19 +19,211 @@ pub struct RustImports {
     pub test_imports: Vec<String>,
     pub extern_mods: Vec<String>,
     pub compile_data: Vec<String>,
+    /// Files that are read at runtime through paths relative to `CARGO_MANIFEST_DIR`, e.g. test
+    /// fixtures. Directories are reported as globs.
+    pub data: Vec<String>,
+    /// Runfiles that are looked up with literal rlocation paths, e.g. `_main/pkg/data.txt`.
+    pub runfiles: Vec<String>,
+    /// Glob patterns of trybuild UI tests, e.g. `tests/ui/*.rs` for
+    /// `TestCases::new().compile_fail("tests/ui/*.rs")`, relative to `CARGO_MANIFEST_DIR`
+    pub trybuild_patterns: Vec<String>,
+    /// Files that a build script reads or watches, relative to `CARGO_MANIFEST_DIR`: paths printed
+    /// with `cargo:rerun-if-changed=`, sources and include directories given to `cc`, and protos
+    /// given to `prost_build` or `tonic_build`. Directories are reported as globs. Only reported
+    /// when `ParseOptions::build_script` is set.
+    pub build_script_data: Vec<String>,
+    /// Cfgs that a build script enables with `cargo:rustc-cfg=`, e.g. `has_foo` or `foo="bar"`.
+    /// Only reported when `ParseOptions::build_script` is set.
+    pub build_script_cfgs: Vec<String>,
+    /// Native libraries linked with `#[link(name = "...")]` on `extern` blocks, sorted
+    pub native_libs: Vec<NativeLib>,
+    /// Protobuf packages whose generated code is included, e.g. `my.pkg.v1` for
+    /// `tonic::include_proto!("my.pkg.v1")` or `include!(concat!(env!("OUT_DIR"), "/my.pkg.v1.rs"))`
+    pub proto_packages: Vec<String>,
+    /// C++ headers included by `#[cxx::bridge]` modules, as written, e.g. `foo/bar.h` for
+    /// `include!("foo/bar.h")`
+    pub cxx_includes: Vec<String>,
+    /// Rust sources that are textually included with `include!`, whose imports are included in
+    /// the imports of this file
+    pub included_srcs: Vec<String>,
+    /// Crates named by paths in the transcribers of `#[macro_export]` macros, e.g.
+    /// `::serde::Serialize`. Crates invoking these macros need the same dependencies.
+    pub exported_macro_imports: Vec<String>,
+    /// The subset of `imports` and `test_imports` that were only found heuristically, see
+    /// `Confidence`.
+    pub heuristic_imports: Vec<String>,
+    /// How each crate in `imports` and `test_imports` is used, which hints at whether it is a
+    /// proc-macro crate.
+    pub crate_usages: Vec<CrateUsage>,
+    /// Crates used by the doctests in doc comments
+    pub doc_test_imports: Vec<String>,
 }
 
-#[derive(Debug, Default, Clone)]
+/// The syntactic position in which a crate is named.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UsageContext {
+    /// In a use item or `extern crate`, e.g. `use foo::Bar;`
+    Use,
+    /// In an ordinary expression or type path, e.g. `foo::bar()`; proc-macro crates can't export
+    /// anything that is used this way
+    Path,
+    /// As a derive macro, e.g. `#[derive(foo::Bar)]`
+    Derive,
+    /// As an attribute, e.g. `#[foo::bar]`
+    Attribute,
+    /// As a function-like macro, e.g. `foo::bar!()`
+    Macro,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrateUsage {
+    pub crate_name: String,
+    /// Sorted and deduplicated
+    pub contexts: Vec<UsageContext>,
+}
+
+/// A native library linked with `#[link(name = "...")]`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NativeLib {
+    pub name: String,
+    /// The kind of the library, e.g. `static`, `dylib` (the default), or `framework`
+    pub kind: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Hints {
     pub has_main: bool,
     pub has_test: bool,
+    /// Has a `#[proc_macro]` or `#[proc_macro_attribute]` function
     pub has_proc_macro: bool,
+    /// Has a `#[proc_macro_derive]` function
+    pub has_proc_macro_derive: bool,
+    /// Has a `#[no_mangle]` or `#[export_name]` function with a non-Rust ABI, e.g. `extern "C"`
+    pub has_c_exports: bool,
+    /// Has `#![no_std]`, possibly behind `cfg_attr`
+    pub no_std: bool,
+    /// Has `#![no_main]`, possibly behind `cfg_attr`
+    pub no_main: bool,
+    /// The values of `#![crate_type = "..."]`, in order
+    pub crate_types: Vec<String>,
+    /// The value of `#![crate_name = "..."]`
+    pub crate_name: Option<String>,
+    /// Has at least one doctest
+    pub has_doc_test: bool,
+    /// Has an insta assertion with an inline snapshot, e.g. `assert_snapshot!(x, @"...")`
+    pub has_inline_snapshot: bool,
+    /// Has an insta assertion whose snapshot is stored in a file
+    pub has_file_snapshot: bool,
+    /// The directories that file snapshots may be stored in, relative to the root of the package:
+    /// `snapshots` next to the source file, and any custom `snapshot_path`
+    pub snapshot_dirs: Vec<String>,
+    /// Has a benchmark: a `#[bench]` function, or a criterion, divan, or iai harness
+    pub has_bench: bool,
+    /// The nightly features enabled with `#![feature(...)]`, possibly behind `cfg_attr`, in order
+    pub nightly_features: Vec<String>,
+    /// Has a `#[cxx::bridge]` module, which needs C++ bindings to be generated
+    pub has_cxx_bridge: bool,
+    /// The name of the Python module defined by a PyO3 `#[pymodule]`: its `name` argument, or
+    /// else the name of the function or module. A crate defining one is a Python extension module.
+    pub python_module: Option<String>,
+    /// Has a PyO3 `#[pyfunction]`
+    pub has_pyfunction: bool,
+    /// Has an item exported to JavaScript with `#[wasm_bindgen]`, e.g. a function, a
+    /// `#[wasm_bindgen(start)]` function, or a struct
+    pub has_wasm_bindgen_exports: bool,
+}
+
+/// The Rust edition of the crate being parsed, which determines how paths are resolved.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Edition {
+    /// Paths are relative to the crate root, and external crates must be declared with
+    /// `extern crate`.
+    Edition2015,
+    Edition2018,
+    #[default]
+    Edition2021,
+    Edition2024,
+}
+
+impl std::str::FromStr for Edition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2015" => Ok(Self::Edition2015),
+            "2018" => Ok(Self::Edition2018),
+            "2021" => Ok(Self::Edition2021),
+            "2024" => Ok(Self::Edition2024),
+            _ => Err(format!("unknown edition: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ParseOptions {
+    /// Features enabled for the crate, used to evaluate `#[cfg(feature = "...")]`
+    pub enabled_features: Vec<String>,
+    pub edition: Edition,
+    /// Helper attribute keys whose values refer to code, in the form `attr.key`, in addition to
+    /// `DEFAULT_PATH_ATTRIBUTES`
+    pub path_attributes: Vec<String>,
+    /// Macros and attributes that read files at compile time, in addition to
+    /// `DEFAULT_COMPILE_DATA_RULES`
+    pub compile_data_rules: Vec<String>,
+    /// Cfgs enabled for the crate, e.g. by its build script, used to evaluate `#[cfg(has_foo)]` and
+    /// `#[cfg(foo = "bar")]`. Values are written as `foo="bar"`.
+    pub enabled_cfgs: Vec<String>,
+    /// Whether the file belongs to a build script, in which case the files that it reads and the
+    /// cfgs that it enables are reported
+    pub build_script: bool,
 }
 
+/// Helper attribute keys whose values refer to code, in the form `attr.key`. String values are
+/// parsed as paths, e.g. `#[serde(with = "humantime_serde")]`, and other values as expressions,
+/// e.g. `#[arg(value_parser = clap::value_parser!(u16))]`.
+pub const DEFAULT_PATH_ATTRIBUTES: &[&str] = &[
+    "serde.crate",
+    "serde.default",
+    "serde.deserialize_with",
+    "serde.from",
+    "serde.getter",
+    "serde.into",
+    "serde.remote",
+    "serde.serialize_with",
+    "serde.try_from",
+    "serde.with",
+    "serde_as.as",
+    "arg.default_value_t",
+    "arg.value_parser",
+    "clap.default_value_t",
+    "clap.value_parser",
+];
+
+/// Macros and attributes that read files at compile time, besides `include_str!` and
+/// `include_bytes!`. Rules have the form `<macro>!=<kind>` or `<attribute>=<kind>`, where the kind
+/// says whether the first string argument of the macro (or the string value of the attribute) is a
+/// `file`, a `dir` or a `glob`. These are relative to the root of the package, or to the directory
+/// of the source file if prefixed with `source_`, e.g. `source_glob`. Any other kind is a glob that
+/// is added whenever the macro or attribute is used.
+pub const DEFAULT_COMPILE_DATA_RULES: &[&str] = &[
+    "include_dir!=dir",
+    // rust-embed
+    "folder=dir",
+    // offline query data
+    "sqlx::query!=.sqlx/query-*.json",
+    "sqlx::query_as!=.sqlx/query-*.json",
+    "sqlx::query_scalar!=.sqlx/query-*.json",
+    "sqlx::query_file!=file",
+    "sqlx::query_file!=.sqlx/query-*.json",
+    "sqlx::query_file_as!=file",
+    "sqlx::query_file_as!=.sqlx/query-*.json",
+    "insta::glob!=source_glob",
+];
+
 pub fn parse_imports(
     absolute_path: PathBuf,
     relative_path: PathBuf,
-    enabled_features: &[String],
+    options: &ParseOptions,
 ) -> Result<RustImports, Box<dyn Error>> {
     // TODO: stream from the file instead of loading it all into memory?
     let mut file = match File::open(&absolute_path) {
@@ -47,54 +240,374 @@ pub fn parse_imports(
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
 
-    parse_imports_from_str(&contents, enabled_features, relative_path)
+    let absolute_dir = absolute_path.parent().map(Path::to_path_buf);
+    parse_imports_impl(&contents, options, relative_path, absolute_dir)
 }
 
+/// Like `parse_imports`, but for contents that aren't read from disk. Files included with
+/// `include!` are reported, but not parsed.
 pub fn parse_imports_from_str(
     contents: &str,
-    enabled_features: &[String],
+    options: &ParseOptions,
+    path: PathBuf,
+) -> Result<RustImports, Box<dyn Error>> {
+    parse_imports_impl(contents, options, path, None)
+}
+
+fn parse_imports_impl(
+    contents: &str,
+    options: &ParseOptions,
     path: PathBuf,
+    absolute_dir: Option<PathBuf>,
 ) -> Result<RustImports, Box<dyn Error>> {
     let ast = parse_file(contents)?;
-    let mut visitor = AstVisitor::new(enabled_features, path);
+    let ctx = FileContext::new(options, path.clone(), absolute_dir);
+    let mut visitor = AstVisitor::new(&ctx);
     visitor.visit_file(&ast);
 
+    let mut doctests = Vec::new();
+    visitor.collect_doctests(&ast.attrs, &ast.items, &mut doctests);
+    visitor.hints.has_doc_test = !doctests.is_empty();
+    if visitor.hints.has_file_snapshot {
+        let default_dir = normalize_path(&ctx.containing_dir.join("snapshots"));
+        if let Some(default_dir) = default_dir.to_str() {
+            visitor.hints.snapshot_dirs.push(default_dir.to_string());
+        }
+        visitor.hints.snapshot_dirs.sort();
+        visitor.hints.snapshot_dirs.dedup();
+    } else {
+        visitor.hints.snapshot_dirs.clear();
+    }
+    let doc_test_imports = parse_doctest_imports(&doctests, options, &path);
+
     let mut root_scope = visitor.mod_stack.pop_back().expect("no root scope");
     assert!(visitor.mod_stack.is_empty(), "leftover scopes");
 
     root_scope.trim_early_imports();
 
-    let import_set: HashSet<_> = root_scope.imports.iter().collect();
+    // absolute imports can't be shadowed, so they skip the trimming above
+    root_scope.imports.extend(visitor.absolute_imports);
+    root_scope
+        .test_imports
+        .extend(visitor.absolute_test_imports);
+
+    let import_set: HashSet<_> = root_scope
+        .imports
+        .iter()
+        .map(|import| &import.ident)
+        .collect();
 
     root_scope
         .test_imports
-        .retain(|test_import| !import_set.contains(test_import));
+        .retain(|test_import| !import_set.contains(&test_import.ident));
+
+    // an import is only heuristic if it was never found definitely
+    let definite_imports: HashSet<_> = root_scope
+        .imports
+        .iter()
+        .chain(&root_scope.test_imports)
+        .filter(|import| import.confidence == Confidence::Definite)
+        .map(|import| &import.ident)
+        .collect();
+    let heuristic_imports: HashSet<_> = root_scope
+        .imports
+        .iter()
+        .chain(&root_scope.test_imports)
+        .map(|import| &import.ident)
+        .filter(|ident| !definite_imports.contains(ident))
+        .cloned()
+        .collect();
+
+    // `compile_fail` and `pass` are common method names, so only trust them alongside trybuild
+    let trybuild_patterns = if root_scope
+        .imports
+        .iter()
+        .chain(&root_scope.test_imports)
+        .any(|import| import.ident == "trybuild")
+    {
+        visitor.trybuild_patterns.into_iter().collect()
+    } else {
+        Vec::new()
+    };
+
+    let crate_usages =
+        collect_crate_usages(root_scope.imports.iter().chain(&root_scope.test_imports));
+
+    // a mod of the same name only shadows exported imports that weren't named absolutely
+    let exported_macro_imports = visitor
+        .exported_macro_imports
+        .into_iter()
+        .filter(|import| !root_scope.mods.contains(import) || import_set.contains(import))
+        .collect();
+
+    let mut hints = visitor.hints;
+    if let Some(in_root_scope) = visitor.bare_bench_main
+        && visitor.bench_main_imported
+    {
+        hints.has_bench = true;
+        hints.has_main |= in_root_scope;
+    }
 
     Ok(RustImports {
-        hints: visitor.hints,
-        imports: filter_imports(root_scope.imports),
-        test_imports: filter_imports(root_scope.test_imports),
+        hints,
+        imports: filter_imports(into_idents(root_scope.imports)),
+        test_imports: filter_imports(into_idents(root_scope.test_imports)),
         extern_mods: visitor.extern_mods.into_iter().collect(),
         compile_data: visitor.compile_data.into_iter().collect(),
+        data: visitor.data.into_iter().collect(),
+        runfiles: visitor.runfiles.into_iter().collect(),
+        trybuild_patterns,
+        build_script_data: visitor.build_script_data.into_iter().collect(),
+        build_script_cfgs: visitor.build_script_cfgs.into_iter().collect(),
+        native_libs: visitor.native_libs.into_iter().collect(),
+        proto_packages: visitor.proto_packages.into_iter().collect(),
+        cxx_includes: visitor.cxx_includes.into_iter().collect(),
+        included_srcs: visitor.included_srcs.into_iter().collect(),
+        exported_macro_imports: filter_imports(exported_macro_imports),
+        heuristic_imports: filter_imports(heuristic_imports.into_iter().collect()),
+        crate_usages,
+        doc_test_imports,
     })
 }
 
+/// Parse the code of each doctest for imports. Like rustdoc, code without a `main` function is
+/// wrapped in one. Doctests that fail to parse are skipped, since rustdoc would fail to compile
+/// them anyway.
+fn parse_doctest_imports(doctests: &[String], options: &ParseOptions, path: &Path) -> Vec<String> {
+    let mut doc_test_imports = BTreeSet::new();
+    for code in doctests {
+        let rust_imports =
+            parse_imports_from_str(code, options, path.to_path_buf()).or_else(|_| {
+                parse_imports_from_str(
+                    &format!("fn main() {{\n{code}}}"),
+                    options,
+                    path.to_path_buf(),
+                )
+            });
+        if let Ok(rust_imports) = rust_imports {
+            doc_test_imports.extend(rust_imports.imports);
+            doc_test_imports.extend(rust_imports.test_imports);
+        }
+    }
+    doc_test_imports.into_iter().collect()
+}
+
+/// Concatenate the doc comments (`///`, `//!`, `#[doc = "..."]`) among some attributes.
+fn doc_comment(attrs: &[syn::Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if let syn::Meta::NameValue(name_value) = &attr.meta
+            && name_value.path.is_ident("doc")
+            && let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) = &name_value.value
+        {
+            lines.push(strip_block_comment_stars(&lit.value()));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Block doc comments (`/** ... */`) often start each line with `*`, which isn't part of the
+/// Markdown.
+fn strip_block_comment_stars(doc: &str) -> String {
+    let mut lines = doc.lines().filter(|line| !line.trim().is_empty());
+    if !doc.contains('\n') || !lines.all(|line| line.trim_start().starts_with('*')) {
+        return doc.to_string();
+    }
+    doc.lines()
+        .map(|line| line.trim_start().strip_prefix('*').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Extract the code of the code blocks in a doc comment that rustdoc compiles as doctests,
+/// including hidden lines.
+fn extract_doctests(doc: &str) -> Vec<String> {
+    let mut doctests = Vec::new();
+    // the opening fence of the current code block and, if it is a doctest, the code so far
+    let mut block: Option<(&str, Option<String>)> = None;
+    for line in doc.lines() {
+        let trimmed = line.trim_start();
+        match &mut block {
+            None => {
+                if let Some(fence) = code_fence(trimmed) {
+                    let code = is_doctest(&trimmed[fence.len()..]).then(String::new);
+                    block = Some((fence, code));
+                }
+            }
+            Some((fence, code)) => {
+                if trimmed.starts_with(*fence) {
+                    doctests.extend(code.take());
+                    block = None;
+                } else if let Some(code) = code {
+                    code.push_str(unhide_doctest_line(trimmed));
+                    code.push('\n');
+                }
+            }
+        }
+    }
+    // rustdoc implicitly closes a code block at the end of the doc comment
+    if let Some((_, Some(code))) = block {
+        doctests.push(code);
+    }
+    doctests
+}
+
+/// Returns the fence if the line opens or closes a code block, e.g. "```".
+fn code_fence(line: &str) -> Option<&str> {
+    let fence_char = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = line.len() - line.trim_start_matches(fence_char).len();
+    (len >= 3).then(|| &line[..len])
+}
+
+/// Returns true if a code block with the given info string (e.g. "rust,no_run") is compiled as a
+/// doctest. Blocks marked `ignore` or `compile_fail` are skipped, as are blocks in other languages.
+fn is_doctest(info: &str) -> bool {
+    let mut seen_rust = false;
+    let mut seen_other = false;
+    for token in info
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+    {
+        match token {
+            "ignore" | "compile_fail" => return false,
+            "rust" => seen_rust = true,
+            "no_run" | "should_panic" | "test_harness" | "standalone_crate" => (),
+            // only ignored on some targets, or only run on some editions
+            _ if token.starts_with("ignore-") || token.starts_with("edition") => (),
+            // error codes for compile_fail
+            _ if token.len() > 1
+                && token.starts_with('E')
+                && token[1..].chars().all(|c| c.is_ascii_digit()) => {}
+            _ => seen_other = true,
+        }
+    }
+    seen_rust || !seen_other
+}
+
+/// Lines starting with `# ` are hidden from the rendered docs, but still compiled.
+fn unhide_doctest_line(line: &str) -> &str {
+    if line == "#" {
+        ""
+    } else if let Some(rest) = line.strip_prefix("# ") {
+        rest
+    } else if line.starts_with("##") {
+        // escaped leading #
+        &line[1..]
+    } else {
+        line
+    }
+}
+
+/// The attributes of an item, if it can have any.
+fn item_attrs(item: &syn::Item) -> Option<&[syn::Attribute]> {
+    match item {
+        syn::Item::Const(node) => Some(&node.attrs),
+        syn::Item::Enum(node) => Some(&node.attrs),
+        syn::Item::ExternCrate(node) => Some(&node.attrs),
+        syn::Item::Fn(node) => Some(&node.attrs),
+        syn::Item::ForeignMod(node) => Some(&node.attrs),
+        syn::Item::Impl(node) => Some(&node.attrs),
+        syn::Item::Macro(node) => Some(&node.attrs),
+        syn::Item::Mod(node) => Some(&node.attrs),
+        syn::Item::Static(node) => Some(&node.attrs),
+        syn::Item::Struct(node) => Some(&node.attrs),
+        syn::Item::Trait(node) => Some(&node.attrs),
+        syn::Item::TraitAlias(node) => Some(&node.attrs),
+        syn::Item::Type(node) => Some(&node.attrs),
+        syn::Item::Union(node) => Some(&node.attrs),
+        syn::Item::Use(node) => Some(&node.attrs),
+        _ => None,
+    }
+}
+
+/// The attributes of the members of an item that can have their own doc comments, e.g. fields and
+/// methods.
+fn member_attrs(item: &syn::Item) -> Vec<&[syn::Attribute]> {
+    match item {
+        syn::Item::Struct(node) => node.fields.iter().map(|field| &field.attrs[..]).collect(),
+        syn::Item::Union(node) => node
+            .fields
+            .named
+            .iter()
+            .map(|field| &field.attrs[..])
+            .collect(),
+        syn::Item::Enum(node) => node
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                std::iter::once(&variant.attrs[..])
+                    .chain(variant.fields.iter().map(|field| &field.attrs[..]))
+            })
+            .collect(),
+        syn::Item::Impl(node) => node
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Const(node) => Some(&node.attrs[..]),
+                syn::ImplItem::Fn(node) => Some(&node.attrs[..]),
+                syn::ImplItem::Type(node) => Some(&node.attrs[..]),
+                syn::ImplItem::Macro(node) => Some(&node.attrs[..]),
+                _ => None,
+            })
+            .collect(),
+        syn::Item::Trait(node) => node
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::TraitItem::Const(node) => Some(&node.attrs[..]),
+                syn::TraitItem::Fn(node) => Some(&node.attrs[..]),
+                syn::TraitItem::Type(node) => Some(&node.attrs[..]),
+                syn::TraitItem::Macro(node) => Some(&node.attrs[..]),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Collect the usage contexts of each crate, dropping those that `filter_imports` would drop.
+fn collect_crate_usages<'a, 'ast: 'a>(
+    imports: impl IntoIterator<Item = &'a Import<'ast>>,
+) -> Vec<CrateUsage> {
+    let mut usages: BTreeMap<String, BTreeSet<UsageContext>> = BTreeMap::new();
+    for import in imports {
+        usages
+            .entry(import.ident.to_string())
+            .or_default()
+            .insert(import.context);
+    }
+
+    usages
+        .into_iter()
+        .filter(|(crate_name, _)| is_crate_name(crate_name))
+        .map(|(crate_name, contexts)| CrateUsage {
+            crate_name,
+            contexts: contexts.into_iter().collect(),
+        })
+        .collect()
+}
+
+fn into_idents(imports: Vec<Import>) -> Vec<Ident> {
+    imports.into_iter().map(|import| import.ident).collect()
+}
+
 fn filter_imports(imports: Vec<Ident>) -> Vec<String> {
     imports
         .into_iter()
-        .filter_map(|ident| {
-            let s = ident.to_string();
-            // uppercase is structs
-            // TODO: don't store all the structs! seems wasteful
-            if s.chars().next().map(|c| c.is_lowercase()).unwrap_or(false) {
-                Some(s)
-            } else {
-                None
-            }
-        })
+        .map(|ident| ident.to_string())
+        .filter(|s| is_crate_name(s))
         .collect()
 }
 
+fn is_crate_name(s: &str) -> bool {
+    // uppercase is structs
+    // TODO: don't store all the structs! seems wasteful
+    s.chars().next().map(|c| c.is_lowercase()).unwrap_or(false)
+}
+
 // Macros aren't parsed as part of the overall AST, so when we parse them we get an owned value.
 // This approach allows us to store both the references and the owned values together, minimzing
 // clones.
@@ -164,6 +677,34 @@ impl Ident<'_> {
     }
 }
 
+/// How sure we are that an import really names a crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Confidence {
+    /// Named by syntax that can only refer to a crate (`extern crate`, a leading `::`) or by a path
+    /// whose first segment is not otherwise in scope.
+    Definite,
+    /// The first segment of a path that could have been brought into scope some other way, e.g. by
+    /// a glob import, or that was found in macro input which may not be Rust syntax at all.
+    Heuristic,
+}
+
+#[derive(Debug, Clone)]
+struct Import<'ast> {
+    ident: Ident<'ast>,
+    confidence: Confidence,
+    context: UsageContext,
+}
+
+impl Import<'_> {
+    fn into_owned<'a>(self) -> Import<'a> {
+        Import {
+            ident: self.ident.into_owned(),
+            confidence: self.confidence,
+            context: self.context,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 struct Scope<'ast> {
     /// mods in scope
@@ -174,10 +715,12 @@ struct Scope<'ast> {
     // TODO: this is not currently used, but we could support #[gazelle::ignore] on things like
     // functions and blocks in the future
     is_ignored: bool,
+    /// whether this scope has a glob import, which may bring anything into scope
+    has_glob: bool,
     /// crates that are imported in this scope
-    imports: Vec<Ident<'ast>>,
+    imports: Vec<Import<'ast>>,
     /// crates that are imported in test-only configurations in this scope
-    test_imports: Vec<Ident<'ast>>,
+    test_imports: Vec<Import<'ast>>,
 }
 
 impl Scope<'_> {
@@ -185,22 +728,173 @@ impl Scope<'_> {
     /// it's possible to access an identifier that's used later in the same or a parent scope, or to
     /// access a module declared later in the file.
     fn trim_early_imports(&mut self) {
-        self.imports.retain(|import| !self.mods.contains(import));
+        self.imports
+            .retain(|import| !self.mods.contains(&import.ident));
         self.test_imports
-            .retain(|test_import| !self.mods.contains(test_import));
+            .retain(|test_import| !self.mods.contains(&test_import.ident));
     }
 }
 
-#[derive(Debug, Clone)]
-struct AstVisitor<'ast> {
+/// Inputs that stay the same for the whole file.
+#[derive(Debug)]
+struct FileContext {
     /// The relative path from the root of Bazel package to the directory that contains the file we
     /// are parsing. This is used to resolve location of files that are included with include_str!
     /// and include_bytes!.
     containing_dir: PathBuf,
+    /// The directory that contains the file on disk, if known. This is used to read files that are
+    /// included with include!.
+    absolute_dir: Option<PathBuf>,
+    /// The root of the Bazel package on disk, if known. This is used to find askama templates.
+    package_dir: Option<PathBuf>,
+    /// Enabled features
+    enabled_features: HashSet<String>,
+    /// Enabled cfgs, see `ParseOptions::enabled_cfgs`
+    enabled_cfgs: HashSet<String>,
+    /// See `ParseOptions::build_script`
+    build_script: bool,
+    edition: Edition,
+    /// Map from helper attributes to their keys whose values refer to code, see
+    /// `DEFAULT_PATH_ATTRIBUTES`
+    path_attributes: HashMap<String, HashSet<String>>,
+    /// See `DEFAULT_COMPILE_DATA_RULES`
+    compile_data_rules: Vec<CompileDataRule>,
+}
+
+impl FileContext {
+    fn new(options: &ParseOptions, path: PathBuf, absolute_dir: Option<PathBuf>) -> Self {
+        let containing_dir = path.parent().unwrap_or_else(|| Path::new("")).to_path_buf();
+        let package_dir = absolute_dir.as_ref().and_then(|absolute_dir| {
+            absolute_dir
+                .ancestors()
+                .nth(containing_dir.components().count())
+                .map(Path::to_path_buf)
+        });
+        Self {
+            containing_dir,
+            absolute_dir,
+            package_dir,
+            enabled_features: options.enabled_features.iter().cloned().collect(),
+            enabled_cfgs: options.enabled_cfgs.iter().cloned().collect(),
+            build_script: options.build_script,
+            edition: options.edition,
+            path_attributes: parse_path_attributes(
+                DEFAULT_PATH_ATTRIBUTES
+                    .iter()
+                    .copied()
+                    .chain(options.path_attributes.iter().map(String::as_str)),
+            ),
+            compile_data_rules: DEFAULT_COMPILE_DATA_RULES
+                .iter()
+                .copied()
+                .chain(options.compile_data_rules.iter().map(String::as_str))
+                .filter_map(CompileDataRule::parse)
+                .collect(),
+        }
+    }
+}
+
+/// Group `attr.key` strings by attribute. Malformed strings are skipped.
+fn parse_path_attributes<'a>(
+    path_attributes: impl IntoIterator<Item = &'a str>,
+) -> HashMap<String, HashSet<String>> {
+    let mut grouped: HashMap<String, HashSet<String>> = HashMap::new();
+    for path_attribute in path_attributes {
+        if let Some((attr, key)) = path_attribute.split_once('.') {
+            grouped
+                .entry(attr.to_string())
+                .or_default()
+                .insert(key.to_string());
+        }
+    }
+    grouped
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum CompileDataKind {
+    File,
+    Dir,
+    Glob,
+    /// A glob that is added regardless of the arguments.
+    Fixed(String),
+}
+
+/// A macro or attribute that reads files at compile time, see `DEFAULT_COMPILE_DATA_RULES`.
+#[derive(Debug)]
+struct CompileDataRule {
+    path: Vec<String>,
+    is_macro: bool,
+    kind: CompileDataKind,
+    relative_to_source: bool,
+}
+
+impl CompileDataRule {
+    /// Parse a rule of the form `<macro>!=<kind>` or `<attribute>=<kind>`. Malformed rules are
+    /// skipped.
+    fn parse(rule: &str) -> Option<Self> {
+        let (target, kind) = rule.split_once('=')?;
+        let (target, is_macro) = match target.strip_suffix('!') {
+            Some(target) => (target, true),
+            None => (target, false),
+        };
+        if target.is_empty() || kind.is_empty() {
+            return None;
+        }
+
+        let (arg_kind, relative_to_source) = match kind.strip_prefix("source_") {
+            Some(arg_kind) => (arg_kind, true),
+            None => (kind, false),
+        };
+        let kind = match arg_kind {
+            "file" => CompileDataKind::File,
+            "dir" => CompileDataKind::Dir,
+            "glob" => CompileDataKind::Glob,
+            _ => CompileDataKind::Fixed(kind.to_string()),
+        };
+
+        Some(Self {
+            path: target.split("::").map(str::to_string).collect(),
+            is_macro,
+            kind,
+            relative_to_source,
+        })
+    }
+
+    /// Whether the rule applies to a macro or attribute with the given path. A qualified path has
+    /// to name the rule's crate, e.g. `sqlx::query!`. A bare path may have been shortened by a
+    /// `use`, e.g. `query!`, in which case the crate has to be imported, as told by
+    /// `crate_imported`.
+    fn matches(
+        &self,
+        path: &syn::Path,
+        is_macro: bool,
+        crate_imported: impl Fn(&str) -> bool,
+    ) -> bool {
+        let (Some(last), Some(rule_last)) = (path.segments.last(), self.path.last()) else {
+            return false;
+        };
+        if self.is_macro != is_macro || last.ident != rule_last {
+            false
+        } else if self.path.len() == 1 {
+            // rules without a crate, e.g. `include_dir!`, match any path with the same name
+            true
+        } else if path.segments.len() > 1 {
+            path.segments[0].ident == self.path[0]
+        } else {
+            crate_imported(&self.path[0])
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AstVisitor<'ast> {
+    ctx: &'ast FileContext,
     /// stack of mods in scope
     mod_stack: VecDeque<Scope<'ast>>,
     /// all mods that are currently in scope (including parent scopes)
     scope_mods: HashSet<Ident<'ast>>,
+    /// mods in scope in the visitors that this visitor is detached from, see `visit_detached`
+    outer_mods: Vec<&'ast HashSet<Ident<'ast>>>,
     /// collected hints
     hints: Hints,
     /// bare mods defined in external files
@@ -208,31 +902,80 @@ struct AstVisitor<'ast> {
     /// mods that are disallowed from being added to the current scope; this is currently only used
     /// for a hack, see below
     mod_denylist: HashSet<Ident<'ast>>,
-    /// Enabled features
-    enabled_features: HashSet<String>,
-    /// Files that are included via include_str! and include_bytes! macros.
+    /// Files that are read at compile time, e.g. by include_str! and include_bytes! macros. Entries
+    /// may be globs, see `DEFAULT_COMPILE_DATA_RULES`.
     compile_data: HashSet<String>,
+    /// Files that are read at runtime, see `manifest_relative_path`.
+    data: HashSet<String>,
+    /// Whether we're inside a path that was added to `data`, so that its prefixes aren't added.
+    inside_data_path: bool,
+    /// Runfiles that are looked up, see `rlocation_path`.
+    runfiles: HashSet<String>,
+    /// Patterns passed to trybuild's `compile_fail` and `pass`.
+    trybuild_patterns: BTreeSet<String>,
+    /// Files that a build script reads, see `visit_build_script_call`.
+    build_script_data: BTreeSet<String>,
+    /// Cfgs that a build script enables.
+    build_script_cfgs: BTreeSet<String>,
+    /// Native libraries linked by `extern` blocks.
+    native_libs: BTreeSet<NativeLib>,
+    /// Protobuf packages whose generated code is included.
+    proto_packages: BTreeSet<String>,
+    /// C++ headers included by cxx bridges.
+    cxx_includes: BTreeSet<String>,
+    /// Rust sources that are included via include! macros.
+    included_srcs: HashSet<String>,
     /// Keep track of whether we're currently inside a use tree to handle aliases correctly.
     inside_use_tree: bool,
+    /// Crates referenced by the transcribers of #[macro_export] macros.
+    exported_macro_imports: HashSet<Ident<'ast>>,
+    /// Crates that are named unambiguously, e.g. by `extern crate` or a leading `::`, and so can't
+    /// be shadowed by mods in scope.
+    absolute_imports: Vec<Import<'ast>>,
+    absolute_test_imports: Vec<Import<'ast>>,
+    /// Whether imports are only guesses because we're visiting macro input, see `Confidence`.
+    heuristic: bool,
+    /// The position of the paths currently being visited.
+    usage_context: UsageContext,
+    /// Whether iai's or iai_callgrind's `main!` is imported, see `imports_bench_main`.
+    bench_main_imported: bool,
+    /// If there is a bare `main!` invocation, whether one is in the root scope. It is only a bench
+    /// harness if `main!` is imported from iai or iai_callgrind, which may happen after it is used.
+    bare_bench_main: Option<bool>,
 }
 
-impl AstVisitor<'_> {
-    fn new(enabled_features: &[String], path: PathBuf) -> Self {
+impl<'ast> AstVisitor<'ast> {
+    fn new(ctx: &'ast FileContext) -> Self {
         let mut mod_stack = VecDeque::new();
         mod_stack.push_back(Scope::default());
 
-        let containing_dir = path.parent().unwrap_or_else(|| Path::new("")).to_path_buf();
-
         Self {
-            containing_dir,
+            ctx,
             mod_stack,
             scope_mods: HashSet::default(),
+            outer_mods: Vec::new(),
             hints: Hints::default(),
             extern_mods: HashSet::new(),
             mod_denylist: HashSet::new(),
-            enabled_features: enabled_features.iter().cloned().collect(),
             compile_data: HashSet::new(),
+            data: HashSet::new(),
+            inside_data_path: false,
+            runfiles: HashSet::new(),
+            trybuild_patterns: BTreeSet::new(),
+            build_script_data: BTreeSet::new(),
+            build_script_cfgs: BTreeSet::new(),
+            native_libs: BTreeSet::new(),
+            proto_packages: BTreeSet::new(),
+            cxx_includes: BTreeSet::new(),
+            included_srcs: HashSet::new(),
             inside_use_tree: false,
+            exported_macro_imports: HashSet::new(),
+            absolute_imports: Vec::new(),
+            absolute_test_imports: Vec::new(),
+            heuristic: false,
+            usage_context: UsageContext::Path,
+            bench_main_imported: false,
+            bare_bench_main: None,
         }
     }
 }
@@ -315,7 +1058,7 @@ impl<'ast> AstVisitor<'ast> {
         match meta {
             syn::Meta::Path(path) => {
                 if let Some(ident) = path.get_ident() {
-                    ident == "test"
+                    ident == "test" || self.ctx.enabled_cfgs.contains(&ident.to_string())
                 } else {
                     true
                 }
@@ -326,7 +1069,25 @@ impl<'ast> AstVisitor<'ast> {
                     && let syn::Expr::Lit(expr_lit) = &nv.value
                     && let syn::Lit::Str(lit) = &expr_lit.lit
                 {
-                    return self.enabled_features.contains(lit.value().as_str());
+                    return self.ctx.enabled_features.contains(lit.value().as_str());
+                }
+                // only cfgs with a value that we know about can be evaluated
+                if let Some(key) = nv.path.get_ident()
+                    && let syn::Expr::Lit(expr_lit) = &nv.value
+                    && let syn::Lit::Str(lit) = &expr_lit.lit
+                {
+                    let prefix = format!("{key}=");
+                    if self
+                        .ctx
+                        .enabled_cfgs
+                        .iter()
+                        .any(|cfg| cfg.starts_with(&prefix))
+                    {
+                        return self
+                            .ctx
+                            .enabled_cfgs
+                            .contains(&format!("{key}={:?}", lit.value()));
+                    }
                 }
                 true
             }
@@ -357,27 +1118,115 @@ impl<'ast> AstVisitor<'ast> {
         }
     }
 
-    fn add_import<I: Into<Ident<'ast>>>(&mut self, ident: I) {
+    /// Record an import in the current scope. Returns whether the import was recorded, i.e. it is
+    /// not a keyword, not a mod that is already in scope, and not in an ignored scope.
+    fn add_import<I: Into<Ident<'ast>>>(&mut self, ident: I) -> bool {
         let ident = ident.into();
 
         if ident == "crate" || ident == "super" || ident == "self" {
             // these are keywords referring to the current crate; not an import
-            return;
+            return false;
         }
 
-        if !self.scope_mods.contains(&ident) && !self.is_ignored_scope() {
-            if self.is_test_only_scope() {
-                self.mod_stack.back_mut().unwrap().test_imports.push(ident);
-            } else {
-                self.mod_stack.back_mut().unwrap().imports.push(ident);
+        if self.in_scope(&ident) || self.is_ignored_scope() {
+            return false;
+        }
+
+        let import = Import {
+            ident,
+            confidence: self.path_confidence(),
+            context: self.usage_context,
+        };
+        if self.is_test_only_scope() {
+            self.mod_stack.back_mut().unwrap().test_imports.push(import);
+        } else {
+            self.mod_stack.back_mut().unwrap().imports.push(import);
+        }
+        true
+    }
+
+    /// Record an import that names a crate regardless of the mods in scope. Returns whether the
+    /// import was recorded.
+    fn add_absolute_import<I: Into<Ident<'ast>>>(&mut self, ident: I) -> bool {
+        let ident = ident.into();
+
+        if ident == "crate" || ident == "super" || ident == "self" || self.is_ignored_scope() {
+            return false;
+        }
+
+        let import = Import {
+            ident,
+            confidence: self.macro_confidence(),
+            context: self.usage_context,
+        };
+        if self.is_test_only_scope() {
+            self.absolute_test_imports.push(import);
+        } else {
+            self.absolute_imports.push(import);
+        }
+        true
+    }
+
+    /// The confidence of an import that names a crate unambiguously.
+    fn macro_confidence(&self) -> Confidence {
+        if self.heuristic {
+            Confidence::Heuristic
+        } else {
+            Confidence::Definite
+        }
+    }
+
+    /// The confidence of an import from the first segment of a path, which could also have been
+    /// brought into scope by a glob import. Paths in use items aren't affected by glob imports.
+    fn path_confidence(&self) -> Confidence {
+        if self.usage_context != UsageContext::Use && self.glob_in_scope() {
+            Confidence::Heuristic
+        } else {
+            self.macro_confidence()
+        }
+    }
+
+    fn glob_in_scope(&self) -> bool {
+        self.mod_stack.iter().any(|scope| scope.has_glob)
+    }
+
+    /// Mark the current scope as having a glob import if any of the given items is a glob use.
+    /// Globs bring names into scope regardless of where they appear, so we check this upfront.
+    fn check_glob_imports(&mut self, items: impl IntoIterator<Item = &'ast syn::Item>) {
+        let has_glob = items.into_iter().any(|item| match item {
+            syn::Item::Use(item_use) => {
+                self.cfg_enabled(&item_use.attrs)
+                    && self.parse_directives(&item_use.attrs).provides.is_empty()
+                    && is_glob_use(&item_use.tree)
             }
+            _ => false,
+        });
+        if has_glob {
+            self.mod_stack.back_mut().unwrap().has_glob = true;
         }
     }
 
+    /// Record the first segment of a path with a leading `::`. Since 2018 this is always an
+    /// external crate, but in 2015 it refers to the crate root.
+    fn add_leading_colon_import<I: Into<Ident<'ast>>>(&mut self, ident: I) -> bool {
+        !self.is_2015() && self.add_absolute_import(ident)
+    }
+
+    /// Record the first segment of a use path. In 2015 use paths are relative to the crate root, so
+    /// only `extern crate` names a crate, while other paths can still name crates in the extern
+    /// prelude.
+    fn add_use_import<I: Into<Ident<'ast>>>(&mut self, ident: I) -> bool {
+        !self.is_2015() && self.add_import(ident)
+    }
+
+    fn is_2015(&self) -> bool {
+        self.ctx.edition == Edition::Edition2015
+    }
+
     fn add_mod<I: Into<Ident<'ast>>>(&mut self, ident: I) {
         let ident = ident.into();
 
-        if !self.scope_mods.contains(&ident) && !self.mod_denylist.contains(&ident) {
+        if !self.in_scope(&ident) && !self.mod_denylist.contains(&ident) {
             self.scope_mods.insert(ident.clone());
             self.mod_stack.back_mut().unwrap().mods.insert(ident);
         }
@@ -391,6 +1240,7 @@ impl<'ast> AstVisitor<'ast> {
             // scopes within test-only scopes are also test-only
             is_test_only: test || current_scope.is_test_only,
             is_ignored: ignored || current_scope.is_ignored,
+            has_glob: false,
             imports: vec![],
             test_imports: vec![],
         });
@@ -411,8 +1261,23 @@ impl<'ast> AstVisitor<'ast> {
         parent_scope.test_imports.extend(scope.test_imports);
     }
 
+    fn in_scope(&self, ident: &Ident) -> bool {
+        self.scope_mods.contains(ident) || self.outer_mods.iter().any(|mods| mods.contains(ident))
+    }
+
+    /// Whether a crate is imported in the current scope or an enclosing one.
+    fn is_imported(&self, krate: &str) -> bool {
+        self.mod_stack.iter().any(|scope| {
+            scope
+                .imports
+                .iter()
+                .chain(&scope.test_imports)
+                .any(|import| import.ident == krate)
+        })
+    }
+
     fn is_root_scope(&self) -> bool {
-        self.mod_stack.len() == 1
+        self.mod_stack.len() == 1 && self.outer_mods.is_empty()
     }
 
     fn is_test_only_scope(&self) -> bool {
@@ -423,20 +1288,29 @@ impl<'ast> AstVisitor<'ast> {
         self.mod_stack.back().unwrap().is_ignored
     }
 
-    fn visit_attr_meta(&mut self, meta: &syn::Meta) {
+    fn visit_attr_meta(&mut self, meta: &syn::Meta, context: UsageContext) {
         // parse #[derive(A, B, ...)] and #[cfg_attr(..., ...)]
         match meta {
-            syn::Meta::Path(path) => {
-                if path.segments.len() > 1 {
-                    self.add_import(
-                        path.segments
-                            .pairs()
-                            .next()
-                            .unwrap()
-                            .into_value()
-                            .ident
-                            .clone(),
-                    );
+            syn::Meta::Path(path) if path.segments.len() > 1 => {
+                let prev_usage_context = std::mem::replace(&mut self.usage_context, context);
+                self.add_import(path.segments[0].ident.clone());
+                self.usage_context = prev_usage_context;
+            }
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }),
+                ..
+            }) => {
+                // e.g. `#[folder = "static/"]`
+                let ctx = self.ctx;
+                for rule in &ctx.compile_data_rules {
+                    if rule.matches(path, false, |krate| self.is_imported(krate)) {
+                        self.add_rule_compile_data(rule, &[lit.value()]);
+                    }
                 }
             }
             syn::Meta::List(list) => {
@@ -446,7 +1320,7 @@ impl<'ast> AstVisitor<'ast> {
                             Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
                         ) {
                             for derive in nested {
-                                self.visit_attr_meta(&derive);
+                                self.visit_attr_meta(&derive, UsageContext::Derive);
                             }
                         }
                     } else if ident == "cfg_attr"
@@ -454,11 +1328,37 @@ impl<'ast> AstVisitor<'ast> {
                             Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
                         )
                     {
-                        // grab second child, which is the inner attribute
-                        let mut iter = nested.into_iter();
-                        iter.next();
-                        if let Some(inner) = iter.next() {
-                            self.visit_attr_meta(&inner);
+                        // skip the predicate; the remaining children are the inner attributes
+                        for inner in nested.iter().skip(1) {
+                            self.visit_attr_meta(inner, UsageContext::Attribute);
+
+                            // Expressions in top-level name-value attributes are visited as part
+                            // of the attribute, but these were parsed from the tokens of the list,
+                            // e.g. `#[cfg_attr(docsrs, doc = include_str!("README.md"))]`.
+                            if let syn::Meta::NameValue(name_value) = inner {
+                                self.visit_detached(&name_value.value, false, |visitor, value| {
+                                    visitor.visit_expr(value)
+                                });
+                            }
+                        }
+                    } else if ident == "template"
+                        && let Ok(nested) = list.parse_args_with(
+                            Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+                        )
+                    {
+                        self.visit_template_attribute(&nested);
+                    } else if let Some(keys) = self.ctx.path_attributes.get(&ident.to_string())
+                        && let Ok(nested) = list.parse_args_with(
+                            Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+                        )
+                    {
+                        for meta in &nested {
+                            if let syn::Meta::NameValue(name_value) = meta
+                                && let Some(key) = name_value.path.get_ident()
+                                && keys.contains(&key.to_string())
+                            {
+                                self.visit_path_attribute_value(&name_value.value);
+                            }
                         }
                     }
                 }
@@ -467,136 +1367,1009 @@ impl<'ast> AstVisitor<'ast> {
         }
     }
 
-    fn parse_directives(&self, attrs: &'ast Vec<syn::Attribute>) -> DirectiveSet {
-        let mut directives = DirectiveSet::default();
-        for attr in attrs {
-            if attr
-                .meta
-                .path()
-                .segments
-                .first()
-                .is_some_and(|seg| seg.ident == "gazelle")
-            {
-                directives.insert(Directive::parse(&attr.meta));
+    /// Visit the value of a helper attribute key that refers to code, see `DEFAULT_PATH_ATTRIBUTES`.
+    fn visit_path_attribute_value(&mut self, value: &syn::Expr) {
+        let syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) = value
+        else {
+            self.visit_detached(value, false, |visitor, value| visitor.visit_expr(value));
+            return;
+        };
+
+        if let Ok(path) = lit.parse::<syn::Path>() {
+            if let Some(ident) = path.get_ident() {
+                // either a crate, e.g. `with = "humantime_serde"`, or a local item, e.g.
+                // `default = "default_port"`
+                let prev_heuristic = std::mem::replace(&mut self.heuristic, true);
+                self.add_import(ident.clone());
+                self.heuristic = prev_heuristic;
+            } else {
+                self.visit_detached(&path, false, |visitor, path| visitor.visit_path(path));
             }
+        } else if let Ok(ty) = lit.parse::<syn::Type>() {
+            self.visit_detached(&ty, false, |visitor, ty| visitor.visit_type(ty));
         }
-        directives
     }
 
-    fn visit_macro_tokens(&mut self, tokens: TokenStream) {
-        if tokens.is_empty() {
+    /// Add the templates used by askama's (or rinja's) `#[template(...)]` as compile data, along
+    /// with the templates they include, extend or import, and the config file. Templates are looked
+    /// up in the dirs configured in the config file, `templates` by default.
+    fn visit_template_attribute(&mut self, nested: &Punctuated<syn::Meta, syn::Token![,]>) {
+        let Some(package_dir) = &self.ctx.package_dir else {
             return;
+        };
+
+        let mut path = None;
+        let mut source = None;
+        let mut config = None;
+        for meta in nested {
+            if let syn::Meta::NameValue(syn::MetaNameValue {
+                path: key,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }),
+                ..
+            }) = meta
+            {
+                if key.is_ident("path") {
+                    path = Some(lit.value());
+                } else if key.is_ident("source") {
+                    source = Some(lit.value());
+                } else if key.is_ident("config") {
+                    config = Some(lit.value());
+                }
+            }
         }
-
-        // Try parsing as an expression
-        if let Ok(expr) = syn::parse2::<syn::Expr>(tokens.clone()) {
-            self.push_scope(false, false);
-            let mut macro_visitor = self.clone();
-            macro_visitor.visit_expr(&expr);
-            self.copy_from_visitor(macro_visitor);
-            self.pop_scope();
+        if path.is_none() && source.is_none() {
             return;
         }
 
-        // Try parsing as a statement
-        if let Ok(stmt) = syn::parse2::<syn::Stmt>(tokens.clone()) {
-            self.push_scope(false, false);
-            let mut macro_visitor = self.clone();
-            macro_visitor.visit_stmt(&stmt);
-            self.copy_from_visitor(macro_visitor);
-            self.pop_scope();
-            return;
+        let config_files = match &config {
+            Some(config) => vec![config.as_str()],
+            None => vec!["askama.toml", "rinja.toml"],
+        };
+        let config = config_files
+            .into_iter()
+            .find_map(|file| Some((file, std::fs::read_to_string(package_dir.join(file)).ok()?)));
+        let dirs = config
+            .as_ref()
+            .and_then(|(_, contents)| parse_template_dirs(contents))
+            .unwrap_or_else(|| vec!["templates".to_string()]);
+        if let Some((file, _)) = config {
+            self.compile_data
+                .insert(normalize_path(Path::new(file)).display().to_string());
         }
 
-        // Still nothing, we need to use some heuristics in order to select a parseable fragment of
-        // the token stream.
-        if starts_with_ident(&tokens) {
-            // The token stream starts with an identifier but does not parse; it must be a list of
-            // arguments separated by commas. Split it on commas and try parsing each argument
-            // individually. We should only retry if we actually encountered some commas, otherwise
-            // we risk running in an infinite loop.
-            let (fragments, seen_commas) = split_on_commas(tokens);
-            if seen_commas {
-                for fragment in fragments {
-                    self.visit_macro_tokens(fragment);
-                }
-            }
-        } else {
-            // The token stream does not start with an identifier: the parseable part probably comes
-            // later. Strip everything until we hit an identifier and try again.
-            self.visit_macro_tokens(remove_prefix_until_ident(tokens))
-        }
-    }
-
-    fn copy_from_visitor(&mut self, other: AstVisitor<'_>) {
-        self.mod_stack = VecDeque::new();
-        for scope in other.mod_stack {
-            let scope_copy = Scope {
-                mods: scope.mods.into_iter().map(Ident::into_owned).collect(),
-                imports: scope.imports.into_iter().map(Ident::into_owned).collect(),
-                test_imports: scope
-                    .test_imports
-                    .into_iter()
-                    .map(Ident::into_owned)
-                    .collect(),
-                is_test_only: scope.is_test_only,
-                is_ignored: scope.is_ignored,
-            };
-            self.mod_stack.push_back(scope_copy);
+        let mut pending: Vec<(String, Option<PathBuf>)> = Vec::new();
+        pending.extend(path.map(|path| (path, None)));
+        if let Some(source) = source {
+            pending.extend(template_references(&source).map(|path| (path, None)));
         }
-        for id in other.scope_mods {
-            let id_copy = match id {
-                Ident::Ref(ident) => Ident::Owned((*ident).clone()),
-                Ident::Owned(ident) => Ident::Owned(ident),
+
+        let mut visited = HashSet::new();
+        while let Some((path, including)) = pending.pop() {
+            // like askama, try relative to the including template before the template dirs
+            let found = including
+                .iter()
+                .filter_map(|including| including.parent())
+                .map(|parent| parent.join(&path))
+                .chain(dirs.iter().map(|dir| Path::new(dir).join(&path)))
+                .map(|candidate| normalize_path(&candidate))
+                .find(|candidate| package_dir.join(candidate).is_file());
+            let Some(found) = found else {
+                continue;
             };
-            self.scope_mods.insert(id_copy);
-        }
-        for id in other.mod_denylist {
-            let id_copy = match id {
-                Ident::Ref(ident) => Ident::Owned((*ident).clone()),
-                Ident::Owned(ident) => Ident::Owned(ident),
+            if !visited.insert(found.clone()) {
+                continue;
+            }
+
+            if let Ok(contents) = std::fs::read_to_string(package_dir.join(&found)) {
+                pending
+                    .extend(template_references(&contents).map(|path| (path, Some(found.clone()))));
+            }
+            let Some(found) = found.to_str() else {
+                continue;
             };
-            self.mod_denylist.insert(id_copy);
+            self.compile_data.insert(found.to_string());
         }
-        self.extern_mods = other.extern_mods;
-        self.compile_data = other.compile_data;
-        self.enabled_features = other.enabled_features;
-        self.hints.has_main = other.hints.has_main;
-        self.hints.has_test = other.hints.has_test;
-        self.hints.has_proc_macro = other.hints.has_proc_macro;
     }
-}
 
-/// Returns true if the path represents a test attribute.
-///
-/// Recognizes:
-/// - Standard: #[test]
-/// - Async/custom test frameworks: any attribute ending in ::test
-///   (e.g., #[tokio::test], #[async_std::test], #[custom::framework::test])
-fn is_test_attribute(path: &syn::Path) -> bool {
-    // Single segment: #[test]
-    if let Some(ident) = path.get_ident() {
-        return ident == "test";
-    }
+    /// Add compile data for a macro that matches a rule in `DEFAULT_COMPILE_DATA_RULES`.
+    fn visit_compile_data_macro(&mut self, mac: &syn::Macro) {
+        let ctx = self.ctx;
+        let rules: Vec<&CompileDataRule> = ctx
+            .compile_data_rules
+            .iter()
+            .filter(|rule| rule.matches(&mac.path, true, |krate| self.is_imported(krate)))
+            .collect();
+        if rules.is_empty() {
+            return;
+        }
 
-    // Multi-segment: check if last segment is "test"
-    path.segments.last().is_some_and(|seg| seg.ident == "test")
-}
+        // only the leading string arguments are of interest, e.g. `include_dir!("assets")` or
+        // `insta::glob!("inputs", "*.txt", |path| ...)`
+        let args: Vec<String> = mac
+            .parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+            .map(|args| args.iter().map_while(str_lit).collect())
+            .unwrap_or_default();
 
-impl<'ast> Visit<'ast> for AstVisitor<'ast> {
-    fn visit_use_tree(&mut self, node: &'ast syn::UseTree) {
-        let prev_inside_use_tree = self.inside_use_tree;
+        for rule in rules {
+            self.add_rule_compile_data(rule, &args);
+        }
+    }
 
-        // Only set inside_use_tree for Path nodes, as these represent nested paths
-        // like `a::b::c`. For other variants (Name, Rename, Group, Glob), we don't
-        // want to set this flag as it would prevent detecting top-level renames
-        // in use groups like `pub use { foo as bar }`.
-        if matches!(node, syn::UseTree::Path(_)) {
-            self.inside_use_tree = true;
+    /// Add the compile data named by the string arguments of a macro or attribute according to
+    /// `rule`. Paths may start with `$CARGO_MANIFEST_DIR`, which is the root of the package.
+    fn add_rule_compile_data(&mut self, rule: &CompileDataRule, args: &[String]) {
+        if self.is_ignored_scope() {
+            return;
+        }
+
+        if let CompileDataKind::Fixed(pattern) = &rule.kind {
+            self.compile_data.insert(pattern.clone());
+            return;
+        }
+
+        let Some(arg) = args.first() else {
+            return;
+        };
+        let mut path = match arg
+            .strip_prefix("$CARGO_MANIFEST_DIR")
+            .or_else(|| arg.strip_prefix("${CARGO_MANIFEST_DIR}"))
+        {
+            Some(rest) => PathBuf::from(rest.trim_start_matches('/')),
+            None if rule.relative_to_source => self.ctx.containing_dir.join(arg),
+            None => PathBuf::from(arg),
+        };
+        if path.is_absolute() {
+            return;
+        }
+        // a second string argument is the pattern within a base directory
+        if rule.kind == CompileDataKind::Glob
+            && let Some(pattern) = args.get(1)
+        {
+            path.push(pattern);
+        }
+
+        let path = normalize_path(&path);
+        let Some(path) = path.to_str() else {
+            return;
+        };
+        self.compile_data.insert(match rule.kind {
+            CompileDataKind::Dir if path.is_empty() => "**".to_string(),
+            CompileDataKind::Dir => format!("{path}/**"),
+            _ => path.to_string(),
+        });
+    }
+
+    /// Visit `insta::with_settings!({key => value, ...}, { ... })`, whose settings aren't ordinary
+    /// Rust syntax. Returns false if the macro doesn't have this shape.
+    fn visit_insta_settings(&mut self, mac: &syn::Macro) -> bool {
+        let tokens: Vec<TokenTree> = mac.tokens.clone().into_iter().collect();
+        let [
+            TokenTree::Group(settings),
+            TokenTree::Punct(comma),
+            TokenTree::Group(body),
+            ..,
+        ] = tokens.as_slice()
+        else {
+            return false;
+        };
+        if settings.delimiter() != proc_macro2::Delimiter::Brace || comma.as_char() != ',' {
+            return false;
+        }
+
+        let settings: Vec<TokenTree> = settings.stream().into_iter().collect();
+        for window in settings.windows(4) {
+            if let [
+                TokenTree::Ident(key),
+                TokenTree::Punct(eq),
+                TokenTree::Punct(gt),
+                value,
+            ] = window
+                && key == "snapshot_path"
+                && eq.as_char() == '='
+                && gt.as_char() == '>'
+                && let Ok(snapshot_path) = syn::parse2::<syn::LitStr>(value.clone().into())
+            {
+                self.add_snapshot_path(&snapshot_path.value());
+            }
+        }
+
+        self.visit_macro_tokens(&body.stream(), false);
+        true
+    }
+
+    /// Add a custom insta snapshot path, which is relative to the source file.
+    fn add_snapshot_path(&mut self, snapshot_path: &str) {
+        let path = normalize_path(&self.ctx.containing_dir.join(snapshot_path));
+        if path.starts_with("..") || path.is_absolute() {
+            return;
+        }
+        if let Some(path) = path.to_str() {
+            self.hints.snapshot_dirs.push(path.to_string());
+        }
+    }
+
+    /// Add a path relative to the root of the package to `data`. Directories are added as globs,
+    /// and paths outside of the package are skipped.
+    fn add_data(&mut self, path: &Path) {
+        if let Some(path) = self.package_file(path) {
+            self.data.insert(path);
+        }
+    }
+
+    /// Normalize a path relative to the root of the package, e.g. `CARGO_MANIFEST_DIR`. Returns
+    /// `None` for paths outside of the package, and a glob for directories.
+    fn package_file(&self, path: &Path) -> Option<String> {
+        let path = normalize_path(path);
+        if path.as_os_str().is_empty() || path.starts_with("..") || path.is_absolute() {
+            return None;
+        }
+        let path_str = path.to_str()?;
+
+        let is_dir = self
+            .ctx
+            .package_dir
+            .as_ref()
+            .is_some_and(|package_dir| package_dir.join(&path).is_dir());
+        Some(if is_dir {
+            format!("{path_str}/**")
+        } else {
+            path_str.to_string()
+        })
+    }
+
+    /// Record the instructions that a build script prints, e.g.
+    /// `println!("cargo:rerun-if-changed=proto/foo.proto")`.
+    fn visit_build_script_println(&mut self, mac: &syn::Macro) {
+        let Ok(args) =
+            mac.parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+        let Some(format) = args.first().and_then(str_lit) else {
+            return;
+        };
+        // both the old `cargo:` and the new `cargo::` syntax
+        let Some(instruction) = format
+            .strip_prefix("cargo::")
+            .or_else(|| format.strip_prefix("cargo:"))
+        else {
+            return;
+        };
+        if instruction.contains('{') {
+            // only literal values can be known statically
+            return;
+        }
+
+        if let Some(path) = instruction.strip_prefix("rerun-if-changed=") {
+            if let Some(path) = self.package_file(Path::new(path)) {
+                self.build_script_data.insert(path);
+            }
+        } else if let Some(cfg) = instruction.strip_prefix("rustc-cfg=") {
+            self.build_script_cfgs.insert(cfg.to_string());
+        }
+    }
+
+    /// Record the files passed to functions that build scripts commonly call, e.g.
+    /// `cc::Build::new().file("src/foo.c")` or `prost_build::compile_protos(&["x.proto"], &["."])`.
+    fn visit_build_script_call(
+        &mut self,
+        name: &syn::Ident,
+        args: &Punctuated<syn::Expr, syn::Token![,]>,
+    ) {
+        let (files, dirs) = if name == "file" || name == "files" {
+            // cc
+            (args.first().map(str_lits).unwrap_or_default(), Vec::new())
+        } else if name == "include" || name == "includes" {
+            (Vec::new(), args.first().map(str_lits).unwrap_or_default())
+        } else if name == "compile_protos" || (name == "compile" && args.len() == 2) {
+            // prost_build and tonic_build, with the protos and then the include dirs; cc's
+            // `compile` takes only the name of the library
+            (
+                args.first().map(str_lits).unwrap_or_default(),
+                args.iter().skip(1).flat_map(str_lits).collect(),
+            )
+        } else {
+            return;
+        };
+
+        for file in files {
+            if let Some(file) = self.package_file(Path::new(&file)) {
+                self.build_script_data.insert(file);
+            }
+        }
+        for dir in dirs {
+            // the root of the package, e.g. `.`, is skipped rather than globbing everything
+            if let Some(dir) = self.package_file(Path::new(&dir)) {
+                let dir = dir.strip_suffix("/**").unwrap_or(&dir);
+                self.build_script_data.insert(format!("{dir}/**"));
+            }
+        }
+    }
+
+    /// Resolve the path given to an include macro, e.g. `include_str!("data.txt")` or
+    /// `include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/data.txt"))`. Returns the path relative
+    /// to the source file, and the path relative to the root of the Bazel package. Absolute paths
+    /// are outside of the package, so they are skipped.
+    fn included_path(&self, mac: &syn::Macro) -> Option<(PathBuf, String)> {
+        let expr = syn::parse2::<syn::Expr>(mac.tokens.clone()).ok()?;
+        if let Some(path) = manifest_relative_path(&expr) {
+            // e.g. `include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))`
+            let combined = normalize_path(&path);
+            if combined.as_os_str().is_empty() || combined.starts_with("..") {
+                return None;
+            }
+            let included_path: PathBuf = self
+                .ctx
+                .containing_dir
+                .components()
+                .map(|_| Path::new(".."))
+                .chain([combined.as_path()])
+                .collect();
+            return Some((included_path, combined.to_str()?.to_string()));
+        }
+
+        let syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) = expr
+        else {
+            return None;
+        };
+
+        let included_path = PathBuf::from(lit.value());
+        if included_path.is_absolute() {
+            return None;
+        }
+
+        let combined = normalize_path(&self.ctx.containing_dir.join(&included_path));
+        let combined = combined.to_str()?.to_string();
+        Some((included_path, combined))
+    }
+
+    /// Record the name of the Python module if the attributes of a function or module mark it as a
+    /// PyO3 `#[pymodule]`. Only the first one is recorded, since the others are usually submodules.
+    fn visit_python_module(&mut self, attrs: &[syn::Attribute], ident: &syn::Ident) {
+        if self.hints.python_module.is_some()
+            || self.is_ignored_scope()
+            || self.is_test_only_scope()
+            || !attrs.iter().any(|attr| {
+                attr.path()
+                    .segments
+                    .last()
+                    .is_some_and(|seg| seg.ident == "pymodule")
+            })
+        {
+            return;
+        }
+
+        let mut name = ident.unraw().to_string();
+        for attr in attrs {
+            // e.g. `#[pymodule(name = "foo")]` or `#[pyo3(name = "foo")]`
+            let Some(last) = attr.path().segments.last() else {
+                continue;
+            };
+            if (last.ident != "pymodule" && last.ident != "pyo3")
+                || !matches!(attr.meta, syn::Meta::List(_))
+            {
+                continue;
+            }
+            let Ok(nested) =
+                attr.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+            else {
+                continue;
+            };
+            for meta in nested {
+                if let syn::Meta::NameValue(name_value) = meta
+                    && name_value.path.is_ident("name")
+                {
+                    match name_value.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }) => name = lit.value(),
+                        syn::Expr::Path(path) => {
+                            if let Some(ident) = path.path.get_ident() {
+                                name = ident.unraw().to_string();
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        self.hints.python_module = Some(name);
+    }
+
+    /// Record whether the attributes of an item export it to JavaScript with `#[wasm_bindgen]`.
+    /// NOTE: `#[wasm_bindgen]` on `extern` blocks imports items instead, so those aren't visited.
+    fn visit_wasm_bindgen_export(&mut self, attrs: &[syn::Attribute]) {
+        if self.is_ignored_scope() || self.is_test_only_scope() {
+            return;
+        }
+        if attrs.iter().any(|attr| {
+            attr.path()
+                .segments
+                .last()
+                .is_some_and(|seg| seg.ident == "wasm_bindgen")
+        }) {
+            self.hints.has_wasm_bindgen_exports = true;
+        }
+    }
+
+    /// Visit an item of a `#[cxx::bridge]` module. The `include!`s in its `extern "C++"` blocks
+    /// name C++ headers rather than Rust sources.
+    fn visit_cxx_bridge_item(&mut self, item: &'ast syn::Item) {
+        let syn::Item::ForeignMod(foreign_mod) = item else {
+            self.visit_item(item);
+            return;
+        };
+
+        for attr in &foreign_mod.attrs {
+            self.visit_attribute(attr);
+        }
+        for foreign_item in &foreign_mod.items {
+            if let syn::ForeignItem::Macro(item_macro) = foreign_item
+                && item_macro.mac.path.is_ident("include")
+            {
+                // e.g. `include!("foo/bar.h")`; system headers, e.g. `include!(<memory>)`, aren't
+                // reported
+                if !self.is_ignored_scope()
+                    && let Ok(header) = item_macro.mac.parse_body::<syn::LitStr>()
+                {
+                    self.cxx_includes.insert(header.value());
+                }
+            } else {
+                self.visit_foreign_item(foreign_item);
+            }
+        }
+    }
+
+    /// Visit a Rust source included with `include!` as if it were written in place, as items or as
+    /// an expression. Sources that can't be read or parsed are skipped.
+    ///
+    /// NOTE: Includes within the included source are resolved relative to the including file
+    /// rather than the included source.
+    fn visit_included_src(&mut self, included_path: &Path) {
+        let Some(absolute_dir) = &self.ctx.absolute_dir else {
+            return;
+        };
+        let Ok(contents) = std::fs::read_to_string(absolute_dir.join(included_path)) else {
+            return;
+        };
+
+        if let Ok(file) = parse_file(&contents) {
+            self.visit_detached(&file, false, |visitor, file| visitor.visit_file(file));
+        } else if let Ok(expr) = syn::parse_str::<syn::Expr>(&contents) {
+            self.visit_detached(&expr, false, |visitor, expr| visitor.visit_expr(expr));
+        }
+    }
+
+    /// Collect the doctests in the doc comments of a module (given its attributes and items),
+    /// including those of its items and their members, recursing into inline modules.
+    fn collect_doctests(
+        &self,
+        attrs: &[syn::Attribute],
+        items: &[syn::Item],
+        doctests: &mut Vec<String>,
+    ) {
+        doctests.extend(extract_doctests(&doc_comment(attrs)));
+        for item in items {
+            let Some(attrs) = item_attrs(item) else {
+                continue;
+            };
+            if !self.cfg_enabled(attrs) {
+                continue;
+            }
+            if let syn::Item::Mod(node) = item {
+                let items = node
+                    .content
+                    .as_ref()
+                    .map_or(&[][..], |(_, items)| &items[..]);
+                self.collect_doctests(attrs, items, doctests);
+            } else {
+                doctests.extend(extract_doctests(&doc_comment(attrs)));
+                for attrs in member_attrs(item) {
+                    if self.cfg_enabled(attrs) {
+                        doctests.extend(extract_doctests(&doc_comment(attrs)));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record hints from an inner attribute of the crate, e.g. `#![no_std]`.
+    fn visit_crate_attr_meta(&mut self, meta: &syn::Meta) {
+        match meta {
+            syn::Meta::Path(path) if path.is_ident("no_std") => self.hints.no_std = true,
+            syn::Meta::Path(path) if path.is_ident("no_main") => self.hints.no_main = true,
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(value),
+                        ..
+                    }),
+                ..
+            }) => {
+                if path.is_ident("crate_type") {
+                    self.hints.crate_types.push(value.value());
+                } else if path.is_ident("crate_name") {
+                    self.hints.crate_name = Some(value.value());
+                }
+            }
+            syn::Meta::List(list) if list.path.is_ident("feature") => {
+                if let Ok(features) =
+                    list.parse_args_with(Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated)
+                {
+                    for feature in features {
+                        let feature = feature.to_string();
+                        if !self.hints.nightly_features.contains(&feature) {
+                            self.hints.nightly_features.push(feature);
+                        }
+                    }
+                }
+            }
+            syn::Meta::List(list) if list.path.is_ident("cfg_attr") => {
+                if let Ok(nested) =
+                    list.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+                {
+                    // skip the predicate; the remaining children are the attributes
+                    for inner in nested.iter().skip(1) {
+                        self.visit_crate_attr_meta(inner);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Record the native library linked by an attribute of an `extern` block, e.g.
+    /// `#[link(name = "ssl", kind = "static")]`, possibly behind `cfg_attr`.
+    fn visit_link_attr_meta(&mut self, meta: &syn::Meta) {
+        let syn::Meta::List(list) = meta else {
+            return;
+        };
+        let Ok(nested) =
+            list.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+
+        if list.path.is_ident("cfg_attr") {
+            if nested
+                .first()
+                .is_some_and(|predicate| self.eval_cfg_meta(predicate))
+            {
+                for inner in nested.iter().skip(1) {
+                    self.visit_link_attr_meta(inner);
+                }
+            }
+        } else if list.path.is_ident("link") {
+            let mut name = None;
+            let mut kind = "dylib".to_string();
+            for meta in &nested {
+                if let syn::Meta::NameValue(nv) = meta
+                    && let Some(value) = str_lit(&nv.value)
+                {
+                    if nv.path.is_ident("name") {
+                        name = Some(value);
+                    } else if nv.path.is_ident("kind") {
+                        kind = value;
+                    }
+                }
+            }
+            // e.g. `#[link(wasm_import_module = "...")]` doesn't link anything
+            if let Some(name) = name {
+                self.native_libs.insert(NativeLib { name, kind });
+            }
+        }
+    }
+
+    fn parse_directives(&self, attrs: &'ast Vec<syn::Attribute>) -> DirectiveSet {
+        let mut directives = DirectiveSet::default();
+        for attr in attrs {
+            if attr
+                .meta
+                .path()
+                .segments
+                .first()
+                .is_some_and(|seg| seg.ident == "gazelle")
+            {
+                directives.insert(Directive::parse(&attr.meta));
+            }
+        }
+        directives
+    }
+
+    /// Visit the rules of a `macro_rules!` definition. Matchers only describe the syntax accepted
+    /// by the macro, so they are skipped. Transcribers are expanded where the macro is invoked, so
+    /// any crates named by paths in them are needed there.
+    fn visit_macro_rules(&mut self, tokens: &TokenStream, exported: bool) {
+        let mut after_arrow = false;
+        for tt in tokens.clone() {
+            match tt {
+                TokenTree::Group(group) if after_arrow => {
+                    self.scan_tokens(group.stream(), TokenScan::Transcriber { exported });
+                    after_arrow = false;
+                }
+                // the second half of `=>`; the matcher is a group, so it can't contain this at the
+                // top level
+                TokenTree::Punct(punct) if punct.as_char() == '>' => after_arrow = true,
+                _ => (),
+            }
+        }
+    }
+
+    /// Visit the tokens of a macro invocation. Macros can contain arbitrary syntax, so we try the
+    /// common shapes of macro input in turn: comma-separated expressions (`println!`, `assert_eq!`,
+    /// `vec!`), then statements. If neither parses, fall back to scanning the tokens for paths.
+    ///
+    /// Only the input of well-known macros is known to be used as ordinary Rust syntax, so imports
+    /// found in any other macro are heuristic.
+    fn visit_macro_tokens(&mut self, tokens: &TokenStream, heuristic: bool) {
+        if tokens.is_empty() {
+            return;
+        }
+
+        if let Ok(exprs) =
+            Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated.parse2(tokens.clone())
+        {
+            self.visit_detached(&exprs, heuristic, |visitor, exprs| {
+                for expr in exprs {
+                    visitor.visit_expr(expr);
+                }
+            });
+        } else if let Ok(stmts) = syn::Block::parse_within.parse2(tokens.clone()) {
+            self.visit_detached(&stmts, heuristic, |visitor, stmts| {
+                for stmt in stmts {
+                    visitor.visit_stmt(stmt);
+                }
+            });
+        } else {
+            let prev_heuristic = std::mem::replace(&mut self.heuristic, true);
+            self.scan_tokens(tokens.clone(), TokenScan::MacroInput);
+            self.heuristic = prev_heuristic;
+        }
+    }
+
+    /// Visit syntax that was parsed out of a macro, and so doesn't live as long as the file's AST.
+    /// Rather than cloning this visitor, the syntax is visited by a lightweight child visitor with a
+    /// single scope that borrows the mods in scope here. Only the imports found by the child are
+    /// converted to owned identifiers when they are merged back into the current scope.
+    fn visit_detached<'m, T: ?Sized>(
+        &'m mut self,
+        node: &'m T,
+        heuristic: bool,
+        visit: impl FnOnce(&mut AstVisitor<'m>, &'m T),
+    ) {
+        let scope = self.mod_stack.back().unwrap();
+        let macro_scope = Scope {
+            is_test_only: scope.is_test_only,
+            is_ignored: scope.is_ignored,
+            has_glob: self.glob_in_scope(),
+            ..Scope::default()
+        };
+
+        let mut outer_mods: Vec<&'m HashSet<Ident<'m>>> = self.outer_mods.clone();
+        outer_mods.push(&self.scope_mods);
+
+        let mut child = AstVisitor {
+            ctx: self.ctx,
+            mod_stack: VecDeque::from([macro_scope]),
+            scope_mods: HashSet::new(),
+            outer_mods,
+            hints: std::mem::take(&mut self.hints),
+            extern_mods: std::mem::take(&mut self.extern_mods),
+            mod_denylist: HashSet::new(),
+            compile_data: std::mem::take(&mut self.compile_data),
+            data: std::mem::take(&mut self.data),
+            inside_data_path: self.inside_data_path,
+            runfiles: std::mem::take(&mut self.runfiles),
+            trybuild_patterns: std::mem::take(&mut self.trybuild_patterns),
+            build_script_data: std::mem::take(&mut self.build_script_data),
+            build_script_cfgs: std::mem::take(&mut self.build_script_cfgs),
+            native_libs: std::mem::take(&mut self.native_libs),
+            proto_packages: std::mem::take(&mut self.proto_packages),
+            cxx_includes: std::mem::take(&mut self.cxx_includes),
+            included_srcs: std::mem::take(&mut self.included_srcs),
+            inside_use_tree: false,
+            exported_macro_imports: HashSet::new(),
+            absolute_imports: Vec::new(),
+            absolute_test_imports: Vec::new(),
+            heuristic: self.heuristic || heuristic,
+            usage_context: UsageContext::Path,
+            bench_main_imported: self.bench_main_imported,
+            bare_bench_main: self.bare_bench_main,
+        };
+        visit(&mut child, node);
+
+        let AstVisitor {
+            mut mod_stack,
+            hints,
+            extern_mods,
+            compile_data,
+            data,
+            runfiles,
+            trybuild_patterns,
+            build_script_data,
+            build_script_cfgs,
+            native_libs,
+            proto_packages,
+            cxx_includes,
+            included_srcs,
+            exported_macro_imports,
+            absolute_imports,
+            absolute_test_imports,
+            bench_main_imported,
+            bare_bench_main,
+            ..
+        } = child;
+
+        self.hints = hints;
+        self.extern_mods = extern_mods;
+        self.compile_data = compile_data;
+        self.data = data;
+        self.runfiles = runfiles;
+        self.trybuild_patterns = trybuild_patterns;
+        self.build_script_data = build_script_data;
+        self.build_script_cfgs = build_script_cfgs;
+        self.native_libs = native_libs;
+        self.proto_packages = proto_packages;
+        self.cxx_includes = cxx_includes;
+        self.included_srcs = included_srcs;
+        self.bench_main_imported = bench_main_imported;
+        self.bare_bench_main = bare_bench_main;
+        self.exported_macro_imports
+            .extend(exported_macro_imports.into_iter().map(Ident::into_owned));
+        self.absolute_imports
+            .extend(absolute_imports.into_iter().map(Import::into_owned));
+        self.absolute_test_imports
+            .extend(absolute_test_imports.into_iter().map(Import::into_owned));
+
+        let mut macro_scope = mod_stack.pop_back().expect("no macro scope");
+        assert!(mod_stack.is_empty(), "leftover macro scopes");
+        macro_scope.trim_early_imports();
+
+        let scope = self.mod_stack.back_mut().unwrap();
+        scope
+            .imports
+            .extend(macro_scope.imports.into_iter().map(Import::into_owned));
+        scope
+            .test_imports
+            .extend(macro_scope.test_imports.into_iter().map(Import::into_owned));
+    }
+
+    /// Find paths in tokens that don't parse as regular syntax. See `TokenScan` for the kinds of
+    /// token streams that are scanned.
+    fn scan_tokens(&mut self, tokens: TokenStream, scan: TokenScan) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+
+        let mut i = 0;
+        while i < tokens.len() {
+            match &tokens[i] {
+                TokenTree::Punct(punct)
+                    if punct.as_char() == '$' && matches!(scan, TokenScan::Transcriber { .. }) =>
+                {
+                    match tokens.get(i + 1) {
+                        // `$crate::...` or `$var::...`; skip the whole path
+                        Some(TokenTree::Ident(_)) => {
+                            i = skip_path(&tokens, i + 2);
+                            continue;
+                        }
+                        // repetition, e.g. `$(...),*`
+                        Some(TokenTree::Group(group)) => {
+                            self.scan_tokens(group.stream(), scan);
+                            i += 2;
+                            continue;
+                        }
+                        _ => (),
+                    }
+                }
+                // groups are scanned rather than parsed again; for deeply nested input like `json!`
+                // objects, parsing every level would be quadratic
+                TokenTree::Group(group) => self.scan_tokens(group.stream(), scan),
+                TokenTree::Ident(_)
+                    if matches!(scan, TokenScan::MacroInput) && is_macro_call(&tokens, i) =>
+                {
+                    // a nested macro call, e.g. `include_str!(...)`
+                    let call: TokenStream = tokens[i..i + 3].iter().cloned().collect();
+                    if let Ok(mac) = syn::parse2::<syn::Macro>(call) {
+                        self.visit_detached(&mac, true, |visitor, mac| visitor.visit_macro(mac));
+                    }
+                    i += 3;
+                    continue;
+                }
+                TokenTree::Ident(ident)
+                    if is_path_sep(&tokens, i + 1)
+                        && !continues_expr(&tokens, i)
+                        && !is_keyword(ident) =>
+                {
+                    self.add_scanned_import(ident.clone(), scan, false);
+                    i = skip_path(&tokens, i + 1);
+                    continue;
+                }
+                TokenTree::Punct(_) if is_leading_path_sep(&tokens, i) => {
+                    if let Some(TokenTree::Ident(ident)) = tokens.get(i + 2) {
+                        self.add_scanned_import(ident.clone(), scan, true);
+                    }
+                    i = skip_path(&tokens, i);
+                    continue;
+                }
+                _ => (),
+            }
+            i += 1;
+        }
+    }
+
+    fn add_scanned_import(&mut self, ident: syn::Ident, scan: TokenScan, leading_colon: bool) {
+        let exported = matches!(scan, TokenScan::Transcriber { exported: true });
+        // a transcriber path without a leading `::` may name a mod at the macro's call site
+        let prev_heuristic = self.heuristic;
+        self.heuristic |= !leading_colon;
+        let added = if leading_colon {
+            self.add_leading_colon_import(ident.clone())
+        } else {
+            self.add_import(ident.clone())
+        };
+        self.heuristic = prev_heuristic;
+        if added && exported {
+            self.exported_macro_imports.insert(ident.into());
+        }
+    }
+}
+
+/// The kinds of token streams that are scanned for paths by `AstVisitor::scan_tokens`.
+#[derive(Debug, Clone, Copy)]
+enum TokenScan {
+    /// The transcriber of a `macro_rules!` rule. Metavariables are ignored and `$crate` refers to
+    /// the crate defining the macro, so neither is an import.
+    Transcriber { exported: bool },
+    /// Macro input that doesn't parse as expressions or statements, e.g. `json!` or `html!`.
+    /// Nested macro calls are parsed again on their own.
+    MacroInput,
+}
+
+/// Standard library macros whose input is ordinary Rust expressions.
+const WELL_KNOWN_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "dbg",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "eprint",
+    "eprintln",
+    "format",
+    "format_args",
+    "matches",
+    "panic",
+    "print",
+    "println",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+];
+
+/// Returns true if the path refers to one of `WELL_KNOWN_MACROS`, either directly or through
+/// `std`, `core`, or `alloc`.
+fn is_well_known_macro(path: &syn::Path) -> bool {
+    let name = match path.segments.len() {
+        1 => &path.segments[0].ident,
+        2 if ["std", "core", "alloc"]
+            .iter()
+            .any(|krate| path.segments[0].ident == krate) =>
+        {
+            &path.segments[1].ident
+        }
+        _ => return false,
+    };
+    WELL_KNOWN_MACROS.iter().any(|known| name == known)
+}
+
+/// Returns true if the use tree contains a glob, e.g. `use foo::*` or `use foo::{bar, baz::*}`.
+fn is_glob_use(use_tree: &syn::UseTree) -> bool {
+    match use_tree {
+        syn::UseTree::Glob(_) => true,
+        syn::UseTree::Path(path) => is_glob_use(&path.tree),
+        syn::UseTree::Group(group) => group.items.iter().any(is_glob_use),
+        _ => false,
+    }
+}
+
+/// Returns true if the ABI is something other than the Rust ABI, e.g. `extern "C"` or `extern`.
+fn is_foreign_abi(abi: &syn::Abi) -> bool {
+    abi.name.as_ref().is_none_or(|name| name.value() != "Rust")
+}
+
+/// Returns true if the attribute exports a symbol under a fixed name, i.e. `#[no_mangle]` or
+/// `#[export_name = "..."]`, including the `#[unsafe(...)]` forms.
+fn is_export_attribute(meta: &syn::Meta) -> bool {
+    match meta {
+        syn::Meta::Path(path) => path.is_ident("no_mangle"),
+        syn::Meta::NameValue(name_value) => name_value.path.is_ident("export_name"),
+        syn::Meta::List(list) if list.path.is_ident("unsafe") => list
+            .parse_args::<syn::Meta>()
+            .is_ok_and(|inner| is_export_attribute(&inner)),
+        _ => false,
+    }
+}
+
+/// Returns true if the path represents a test attribute.
+///
+/// Recognizes:
+/// - Standard: #[test]
+/// - Async/custom test frameworks: any attribute ending in ::test
+///   (e.g., #[tokio::test], #[async_std::test], #[custom::framework::test])
+fn is_test_attribute(path: &syn::Path) -> bool {
+    // Single segment: #[test]
+    if let Some(ident) = path.get_ident() {
+        return ident == "test";
+    }
+
+    // Multi-segment: check if last segment is "test"
+    path.segments.last().is_some_and(|seg| seg.ident == "test")
+}
+
+/// Returns true if the path is a benchmark attribute, e.g. `#[bench]`, `#[divan::bench]`, or
+/// `#[library_benchmark]`.
+fn is_bench_attribute(path: &syn::Path) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|seg| seg.ident == "bench" || seg.ident == "library_benchmark")
+}
+
+/// Returns true if the path is the attribute of a cxx bridge module, i.e. `#[cxx::bridge]`.
+fn is_cxx_bridge_attribute(path: &syn::Path) -> bool {
+    path.segments.len() == 2
+        && path.segments[0].ident == "cxx"
+        && path.segments[1].ident == "bridge"
+}
+
+/// If the macro belongs to a benchmark harness, returns whether it generates a `main` function,
+/// e.g. `criterion_main!` or `iai::main!`.
+fn bench_harness_macro(path: &syn::Path) -> Option<bool> {
+    let name = &path.segments.last()?.ident;
+    let krate = &path.segments.first()?.ident;
+    if name == "criterion_main" {
+        Some(true)
+    } else if name == "main" {
+        // a bare `main!` is checked against the imports instead, see `AstVisitor::bare_bench_main`
+        (krate == "iai" || krate == "iai_callgrind").then_some(true)
+    } else if name == "criterion_group"
+        || name == "library_benchmark_group"
+        || name == "binary_benchmark_group"
+    {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+impl<'ast> Visit<'ast> for AstVisitor<'ast> {
+    fn visit_use_tree(&mut self, node: &'ast syn::UseTree) {
+        let prev_inside_use_tree = self.inside_use_tree;
+
+        // Only set inside_use_tree for Path nodes, as these represent nested paths
+        // like `a::b::c`. For other variants (Name, Rename, Group, Glob), we don't
+        // want to set this flag as it would prevent detecting top-level renames
+        // in use groups like `pub use { foo as bar }`.
+        if matches!(node, syn::UseTree::Path(_)) {
+            self.inside_use_tree = true;
         }
 
         if !prev_inside_use_tree && let syn::UseTree::Rename(rename) = node {
-            self.add_import(&rename.ident);
+            self.add_use_import(&rename.ident);
         }
 
         visit::visit_use_tree(self, node);
@@ -613,7 +2386,9 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
     }
 
     fn visit_path(&mut self, node: &'ast syn::Path) {
-        if node.segments.len() > 1 {
+        if node.leading_colon.is_some() {
+            self.add_leading_colon_import(&node.segments[0].ident);
+        } else if node.segments.len() > 1 {
             self.add_import(&node.segments[0].ident);
         }
         visit::visit_path(self, node);
@@ -627,6 +2402,7 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
         let directives = self.parse_directives(&node.attrs);
 
         let mut imports = HashSet::new();
+        let prev_usage_context = std::mem::replace(&mut self.usage_context, UsageContext::Use);
 
         // NOTE: We want to ignore any dependencies inside the ignored scope. However, we still want
         // to bring anything imported into scope, hence the visit::visit_item_use outside the
@@ -634,9 +2410,16 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
         if !directives.should_ignore {
             parse_use_imports(&node.tree, &mut imports);
         }
+        if imports_bench_main(&node.tree, None) {
+            self.bench_main_imported = true;
+        }
 
         for import in &imports {
-            self.add_import(import.clone());
+            if node.leading_colon.is_some() {
+                self.add_leading_colon_import(import.clone());
+            } else {
+                self.add_use_import(import.clone());
+            }
         }
 
         // Name-only uses, e.g. `use foobar;`, don't bring anything new into scope that isn't
@@ -647,6 +2430,7 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
         self.mod_denylist = imports;
         visit::visit_item_use(self, node);
         self.mod_denylist.clear();
+        self.usage_context = prev_usage_context;
 
         for provided in directives.provides {
             self.add_mod(provided);
@@ -675,7 +2459,9 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
 
         let directives = self.parse_directives(&node.attrs);
         if !directives.should_ignore {
-            self.add_import(&node.ident);
+            let prev_usage_context = std::mem::replace(&mut self.usage_context, UsageContext::Use);
+            self.add_absolute_import(&node.ident);
+            self.usage_context = prev_usage_context;
         }
 
         for provided in directives.provides {
@@ -683,8 +2469,22 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
         }
     }
 
+    fn visit_file(&mut self, node: &'ast syn::File) {
+        for attr in &node.attrs {
+            if let syn::AttrStyle::Inner(_) = attr.style {
+                self.visit_crate_attr_meta(&attr.meta);
+            }
+        }
+        self.check_glob_imports(&node.items);
+        visit::visit_file(self, node);
+    }
+
     fn visit_block(&mut self, node: &'ast syn::Block) {
         self.push_scope(false, false);
+        self.check_glob_imports(node.stmts.iter().filter_map(|stmt| match stmt {
+            syn::Stmt::Item(item) => Some(item),
+            _ => None,
+        }));
         visit::visit_block(self, node);
         self.pop_scope();
     }
@@ -717,12 +2517,49 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
             self.extern_mods.insert(node.ident.unraw().to_string());
         }
 
+        self.visit_python_module(&node.attrs, &node.ident);
+
+        let is_cxx_bridge = node
+            .attrs
+            .iter()
+            .any(|attr| is_cxx_bridge_attribute(attr.path()));
+        if is_cxx_bridge && !self.is_ignored_scope() {
+            self.hints.has_cxx_bridge = true;
+        }
+
         self.add_mod(&node.ident);
         self.push_scope(is_test_only, false);
-        visit::visit_item_mod(self, node);
+        if let Some((_, items)) = &node.content {
+            self.check_glob_imports(items);
+        }
+        if is_cxx_bridge {
+            for attr in &node.attrs {
+                self.visit_attribute(attr);
+            }
+            for item in node.content.iter().flat_map(|(_, items)| items) {
+                self.visit_cxx_bridge_item(item);
+            }
+        } else {
+            visit::visit_item_mod(self, node);
+        }
         self.pop_scope();
     }
 
+    fn visit_item_foreign_mod(&mut self, node: &'ast syn::ItemForeignMod) {
+        if !self.cfg_enabled(&node.attrs) {
+            return;
+        }
+
+        // NOTE: Libraries linked only by tests aren't reported, since they'd be added to the deps of
+        // the crate itself.
+        if !self.is_ignored_scope() && !self.is_test_only_scope() {
+            for attr in &node.attrs {
+                self.visit_link_attr_meta(&attr.meta);
+            }
+        }
+        visit::visit_item_foreign_mod(self, node);
+    }
+
     fn visit_item_fn(&mut self, node: &'ast syn::ItemFn) {
         if !self.cfg_enabled(&node.attrs) {
             return;
@@ -740,10 +2577,20 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
                         if is_test_attribute(path) {
                             self.hints.has_test = true;
                             is_test_only = true;
+                        } else if is_bench_attribute(path) {
+                            self.hints.has_bench = true;
                         } else if let Some(ident) = path.get_ident()
                             && (ident == "proc_macro" || ident == "proc_macro_attribute")
                         {
                             self.hints.has_proc_macro = true;
+                        } else if path.is_ident("proc_macro_derive") {
+                            self.hints.has_proc_macro_derive = true;
+                        } else if path
+                            .segments
+                            .last()
+                            .is_some_and(|seg| seg.ident == "pyfunction")
+                        {
+                            self.hints.has_pyfunction = true;
                         }
                     }
                     _ => {}
@@ -751,26 +2598,45 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
             }
         }
 
+        self.visit_python_module(&node.attrs, &node.sig.ident);
+        self.visit_wasm_bindgen_export(&node.attrs);
+
+        if node.sig.abi.as_ref().is_some_and(is_foreign_abi)
+            && node
+                .attrs
+                .iter()
+                .any(|attr| is_export_attribute(&attr.meta))
+        {
+            self.hints.has_c_exports = true;
+        }
+
         self.push_scope(is_test_only, false);
         visit::visit_item_fn(self, node);
         self.pop_scope();
     }
 
     fn visit_item_struct(&mut self, node: &'ast syn::ItemStruct) {
+        self.visit_wasm_bindgen_export(&node.attrs);
         visit::visit_item_struct(self, node);
     }
 
     fn visit_item_enum(&mut self, node: &'ast syn::ItemEnum) {
+        self.visit_wasm_bindgen_export(&node.attrs);
         visit::visit_item_enum(self, node);
     }
 
-    fn visit_item_type(&mut self, node: &'ast syn::ItemType) {
-        visit::visit_item_type(self, node);
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        self.visit_wasm_bindgen_export(&node.attrs);
+        visit::visit_item_impl(self, node);
     }
 
     fn visit_attribute(&mut self, node: &'ast syn::Attribute) {
-        self.visit_attr_meta(&node.meta);
+        self.visit_attr_meta(&node.meta, UsageContext::Attribute);
+
+        let prev_usage_context =
+            std::mem::replace(&mut self.usage_context, UsageContext::Attribute);
         visit::visit_attribute(self, node);
+        self.usage_context = prev_usage_context;
     }
 
     fn visit_item_macro(&mut self, node: &'ast syn::ItemMacro) {
@@ -785,15 +2651,93 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
             && let Some(new_ident) = &node.ident
         {
             self.add_mod(new_ident);
-        }
 
-        visit::visit_item_macro(self, node);
+            let exported = node
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("macro_export"));
+            for attr in &node.attrs {
+                self.visit_attribute(attr);
+            }
+            self.visit_macro_rules(&node.mac.tokens, exported);
+        } else {
+            if let Some(has_main) = bench_harness_macro(&node.mac.path) {
+                self.hints.has_bench = true;
+                if has_main && self.is_root_scope() {
+                    self.hints.has_main = true;
+                }
+            } else if node.mac.path.is_ident("main") {
+                let in_root_scope = self.is_root_scope();
+                self.bare_bench_main = Some(self.bare_bench_main.unwrap_or(false) || in_root_scope);
+            }
+            visit::visit_item_macro(self, node);
+        }
 
         for provided in directives.provides {
             self.add_mod(provided);
         }
     }
 
+    fn visit_expr(&mut self, node: &'ast syn::Expr) {
+        if !self.is_ignored_scope()
+            && let Some(runfile) = rlocation_path(node)
+        {
+            self.runfiles.insert(runfile);
+        }
+
+        if self.ctx.build_script && !self.is_ignored_scope() {
+            match node {
+                syn::Expr::MethodCall(call) => {
+                    self.visit_build_script_call(&call.method, &call.args)
+                }
+                syn::Expr::Call(call) => {
+                    if let syn::Expr::Path(func) = &*call.func
+                        && let Some(segment) = func.path.segments.last()
+                    {
+                        self.visit_build_script_call(&segment.ident, &call.args);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let syn::Expr::Call(call) = node
+            && let syn::Expr::Path(func) = &*call.func
+            && func.path.segments.len() == 2
+            && func.path.segments[0].ident == "divan"
+            && func.path.segments[1].ident == "main"
+        {
+            // e.g. `fn main() { divan::main() }`
+            self.hints.has_bench = true;
+        }
+
+        if let syn::Expr::MethodCall(call) = node
+            && call.args.len() == 1
+            && let Some(arg) = str_lit(&call.args[0])
+        {
+            if call.method == "set_snapshot_path" {
+                // e.g. `settings.set_snapshot_path("golden")`
+                self.add_snapshot_path(&arg);
+            } else if call.method == "compile_fail" || call.method == "pass" {
+                // e.g. `trybuild::TestCases::new().compile_fail("tests/ui/*.rs")`
+                self.trybuild_patterns.insert(arg);
+            }
+        }
+
+        if !self.inside_data_path
+            && !self.is_ignored_scope()
+            && let Some(path) = data_path(node)
+        {
+            self.add_data(&path);
+
+            let prev_inside_data_path = std::mem::replace(&mut self.inside_data_path, true);
+            visit::visit_expr(self, node);
+            self.inside_data_path = prev_inside_data_path;
+        } else {
+            visit::visit_expr(self, node);
+        }
+    }
+
     fn visit_macro(&mut self, mac: &'ast syn::Macro) {
         let macro_ident = mac.path.get_ident();
 
@@ -804,29 +2748,67 @@ impl<'ast> Visit<'ast> for AstVisitor<'ast> {
                 return;
             }
 
-            if let Ok(syn::Expr::Lit(syn::ExprLit {
-                lit: syn::Lit::Str(lit),
-                ..
-            })) = syn::parse2::<syn::Expr>(mac.tokens.clone())
-            {
-                let included_path = PathBuf::from(lit.value());
+            if let Some((_, included)) = self.included_path(mac) {
+                self.compile_data.insert(included);
+            }
+        } else if let Some(ident) = macro_ident
+            && ident == "include"
+        {
+            if self.is_ignored_scope() {
+                return;
+            }
 
-                if included_path.is_absolute() {
-                    panic!(
-                        "included paths must not be absolute: {}",
-                        included_path.display()
-                    )
+            if let Some(package) = out_dir_include(mac) {
+                // generated code, e.g. by prost, which can't be read
+                self.proto_packages.insert(package);
+            } else if let Some((included_path, included)) = self.included_path(mac)
+                && self.included_srcs.insert(included)
+            {
+                self.visit_included_src(&included_path);
+            }
+            return;
+        }
+        if mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "include_proto")
+            && !self.is_ignored_scope()
+            && let Ok(package) = mac.parse_body::<syn::LitStr>()
+        {
+            // e.g. `tonic::include_proto!("my.pkg.v1")`
+            self.proto_packages.insert(package.value());
+        }
+        if let Some(ident) = macro_ident
+            && ident == "println"
+            && self.ctx.build_script
+            && !self.is_ignored_scope()
+        {
+            self.visit_build_script_println(mac);
+        }
+        if let Some(name) = mac.path.segments.last().map(|segment| &segment.ident) {
+            if name == "with_settings" && self.visit_insta_settings(mac) {
+                return;
+            }
+            let name = name.to_string();
+            if name.starts_with("assert_") && name.ends_with("_snapshot") {
+                // inline snapshots follow an `@`, e.g. `assert_snapshot!(x, @"...")`
+                let inline = mac.tokens.clone().into_iter().any(
+                    |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == '@'),
+                );
+                if inline {
+                    self.hints.has_inline_snapshot = true;
                 } else {
-                    let combined = self.containing_dir.join(included_path);
-                    match normalize_path(&combined).to_str() {
-                        None => panic!("Invalid unicode in the path: {}", combined.display()),
-                        Some(x) => self.compile_data.insert(x.to_string()),
-                    };
+                    self.hints.has_file_snapshot = true;
                 }
             }
         }
-        self.visit_macro_tokens(mac.tokens.clone());
+        self.visit_compile_data_macro(mac);
+        self.visit_macro_tokens(&mac.tokens, !is_well_known_macro(&mac.path));
+
+        let prev_usage_context = std::mem::replace(&mut self.usage_context, UsageContext::Macro);
         visit::visit_macro(self, mac);
+        self.usage_context = prev_usage_context;
     }
 }
 
@@ -847,6 +2829,23 @@ fn parse_use_imports<'ast>(use_tree: &'ast syn::UseTree, imports: &mut HashSet<I
     }
 }
 
+/// Whether a use tree imports the `main!` macro of iai or iai_callgrind, e.g.
+/// `use iai_callgrind::{library_benchmark_group, main};` or `use iai::*;`. `krate` is the first
+/// segment of the path so far.
+fn imports_bench_main(use_tree: &syn::UseTree, krate: Option<&syn::Ident>) -> bool {
+    let is_iai = krate.is_some_and(|krate| krate == "iai" || krate == "iai_callgrind");
+    match use_tree {
+        syn::UseTree::Path(path) => imports_bench_main(&path.tree, krate.or(Some(&path.ident))),
+        syn::UseTree::Name(name) => is_iai && name.ident == "main",
+        syn::UseTree::Rename(rename) => is_iai && rename.ident == "main" && rename.rename == "main",
+        syn::UseTree::Glob(_) => is_iai,
+        syn::UseTree::Group(group) => group
+            .items
+            .iter()
+            .any(|item| imports_bench_main(item, krate)),
+    }
+}
+
 /// Normalize a path by resolving `.` and `..` without touching the filesystem
 fn normalize_path(path: &Path) -> PathBuf {
     let mut stack = Vec::new();
@@ -873,46 +2872,297 @@ fn normalize_path(path: &Path) -> PathBuf {
     stack.iter().collect()
 }
 
-/// Remove all tokens before the first `Ident`. Return the remaining `TokenStream` starting with
-/// that `Ident`.
-fn remove_prefix_until_ident(ts: TokenStream) -> TokenStream {
-    ts.into_iter()
-        .skip_while(|tt| !matches!(tt, TokenTree::Ident(_)))
-        .collect()
+/// If `expr` is a path relative to `CARGO_MANIFEST_DIR` that can be resolved statically, return
+/// that relative path, e.g. for `Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data.json")` or
+/// `concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data.json")`. The manifest dir itself is an empty
+/// path.
+fn manifest_relative_path(expr: &syn::Expr) -> Option<PathBuf> {
+    match expr {
+        syn::Expr::Macro(syn::ExprMacro { mac, .. }) => {
+            let name = &mac.path.segments.last()?.ident;
+            // avoid parsing the bodies of unrelated macros, which may not be expressions
+            if name != "env" && name != "concat" {
+                return None;
+            }
+            let args = mac
+                .parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+                .ok()?;
+            if name == "env" {
+                (args.len() == 1 && str_lit(&args[0])? == "CARGO_MANIFEST_DIR").then(PathBuf::new)
+            } else {
+                let mut args = args.iter();
+                let mut path = manifest_relative_path(args.next()?)?;
+                let rest = args.map(str_lit).collect::<Option<String>>()?;
+                path.push(rest.trim_start_matches('/'));
+                Some(path)
+            }
+        }
+        syn::Expr::MethodCall(call) if call.method == "join" && call.args.len() == 1 => {
+            let mut path = manifest_relative_path(&call.receiver)?;
+            let joined = str_lit(&call.args[0])?;
+            // joining an absolute path replaces the manifest dir
+            if Path::new(&joined).is_absolute() {
+                return None;
+            }
+            path.push(joined);
+            Some(path)
+        }
+        // e.g. `Path::new(...)` or `PathBuf::from(...)`
+        syn::Expr::Call(syn::ExprCall { func, args, .. }) if args.len() == 1 => {
+            let syn::Expr::Path(func) = &**func else {
+                return None;
+            };
+            let mut segments = func.path.segments.iter().rev();
+            let (constructor, ty) = (&segments.next()?.ident, &segments.next()?.ident);
+            if (ty == "Path" && constructor == "new") || (ty == "PathBuf" && constructor == "from")
+            {
+                manifest_relative_path(&args[0])
+            } else {
+                None
+            }
+        }
+        syn::Expr::Paren(syn::ExprParen { expr, .. })
+        | syn::Expr::Reference(syn::ExprReference { expr, .. }) => manifest_relative_path(expr),
+        _ => None,
+    }
 }
 
-/// Split a `TokenStream` on commas, returning:
-/// - A `Vec` of `TokenStreams` (segments without the commas)
-/// - A bool indicating whether any comma was seen
-fn split_on_commas(ts: TokenStream) -> (Vec<TokenStream>, bool) {
-    let mut result = Vec::new();
-    let mut current = Vec::new();
-    let mut seen_comma = false;
+/// If `expr` builds a path relative to `CARGO_MANIFEST_DIR` with `Path::new`, `PathBuf::from` or
+/// `join`, or passes one to a `std::fs` function or `File::open`, return that relative path. Other
+/// uses of the manifest dir, e.g. in `include_str!`, aren't read at runtime.
+fn data_path(expr: &syn::Expr) -> Option<PathBuf> {
+    match expr {
+        syn::Expr::Call(syn::ExprCall { func, args, .. }) if is_fs_function(func) => {
+            manifest_relative_path(args.first()?)
+        }
+        syn::Expr::Call(_) | syn::Expr::MethodCall(_) => manifest_relative_path(expr),
+        _ => None,
+    }
+}
 
-    for tt in ts {
-        match &tt {
-            TokenTree::Punct(punct) if punct.as_char() == ',' => {
-                seen_comma = true;
-                // Push current segment and start a new one
-                result.push(current.into_iter().collect());
-                current = Vec::new();
-            }
-            _ => current.push(tt),
+/// Whether `func` is a function that reads the path it is given, e.g. `fs::read_to_string` or
+/// `File::open`.
+fn is_fs_function(func: &syn::Expr) -> bool {
+    let syn::Expr::Path(func) = func else {
+        return false;
+    };
+    let segments: Vec<String> = func
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    segments.iter().any(|segment| segment == "fs")
+        || segments.ends_with(&["File".into(), "open".into()])
+}
+
+/// If `expr` looks up a runfile by a literal path, return that path, e.g. for
+/// `rlocation!(r, "_main/pkg/data.txt")`, `r.rlocation("_main/pkg/data.txt")` or
+/// `Runfiles::rlocation(&r, "_main/pkg/data.txt")`.
+fn rlocation_path(expr: &syn::Expr) -> Option<String> {
+    let is_rlocation = |path: &syn::Path| {
+        path.segments
+            .last()
+            .is_some_and(|segment| segment.ident == "rlocation")
+    };
+
+    match expr {
+        syn::Expr::Macro(syn::ExprMacro { mac, .. }) if is_rlocation(&mac.path) => {
+            let args = mac
+                .parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+                .ok()?;
+            str_lit(args.iter().nth(1)?)
+        }
+        syn::Expr::MethodCall(call)
+            if call.method == "rlocation" || call.method == "rlocation_from" =>
+        {
+            str_lit(call.args.first()?)
         }
+        syn::Expr::Call(syn::ExprCall { func, args, .. }) => match &**func {
+            syn::Expr::Path(func) if is_rlocation(&func.path) => str_lit(args.iter().nth(1)?),
+            _ => None,
+        },
+        _ => None,
     }
+}
+
+fn str_lit(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Some(lit.value()),
+        _ => None,
+    }
+}
 
-    // Push the last segment if any tokens remain
-    if !current.is_empty() || !seen_comma {
-        result.push(current.into_iter().collect());
+/// If the macro includes a Rust file generated in `OUT_DIR`, e.g.
+/// `include!(concat!(env!("OUT_DIR"), "/my.pkg.rs"))`, returns the name of the file without the
+/// extension, which for prost is the protobuf package.
+fn out_dir_include(mac: &syn::Macro) -> Option<String> {
+    let syn::Expr::Macro(concat) = mac.parse_body::<syn::Expr>().ok()? else {
+        return None;
+    };
+    if !concat.mac.path.is_ident("concat") {
+        return None;
+    }
+    let args = concat
+        .mac
+        .parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+        .ok()?;
+    let [syn::Expr::Macro(env), file] = args.iter().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    if !env.mac.path.is_ident("env")
+        || env.mac.parse_body::<syn::LitStr>().ok()?.value() != "OUT_DIR"
+    {
+        return None;
     }
+    str_lit(file)?
+        .strip_prefix('/')?
+        .strip_suffix(".rs")
+        .filter(|name| !name.contains('/'))
+        .map(str::to_string)
+}
 
-    (result, seen_comma)
+/// The string literals in a string or a list of strings, e.g. `"a"`, `&["a", "b"]`, or
+/// `vec!["a", "b"]`.
+fn str_lits(expr: &syn::Expr) -> Vec<String> {
+    match expr {
+        syn::Expr::Lit(_) => str_lit(expr).into_iter().collect(),
+        syn::Expr::Reference(reference) => str_lits(&reference.expr),
+        syn::Expr::Paren(paren) => str_lits(&paren.expr),
+        syn::Expr::Array(array) => array.elems.iter().filter_map(str_lit).collect(),
+        syn::Expr::Macro(mac) if mac.mac.path.is_ident("vec") => mac
+            .mac
+            .parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+            .map(|elems| elems.iter().filter_map(str_lit).collect())
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
 }
 
-/// Return true if the given `TokenStream` starts with an `Ident`.
-fn starts_with_ident(ts: &TokenStream) -> bool {
-    ts.clone()
-        .into_iter()
-        .next()
-        .is_some_and(|tt| matches!(tt, TokenTree::Ident(_)))
+/// Read the template dirs from an askama config file, i.e. `dirs` in the `[general]` table. This
+/// only understands arrays of plain strings, which is all the config allows.
+fn parse_template_dirs(config: &str) -> Option<Vec<String>> {
+    let mut in_general = false;
+    let mut lines = config.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_general = line.trim_start_matches('[').trim_end_matches(']').trim() == "general";
+            continue;
+        }
+        if !in_general {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if key.trim() != "dirs" {
+            continue;
+        }
+
+        // the array may span several lines
+        let mut value = value.to_string();
+        while !value.contains(']') {
+            let Some(next) = lines.next() else { break };
+            value.push_str(next);
+        }
+        let (_, value) = value.split_once('[')?;
+        let value = value.split(']').next().unwrap_or_default();
+        let dirs = value
+            .split(',')
+            .map(|dir| dir.trim().trim_matches(['"', '\'']))
+            .filter(|dir| !dir.is_empty())
+            .map(str::to_string)
+            .collect();
+        return Some(dirs);
+    }
+    None
+}
+
+/// Find the templates named by `{% include %}`, `{% extends %}` and `{% import %}` tags.
+///
+/// NOTE: This assumes the default block delimiters.
+fn template_references(source: &str) -> impl Iterator<Item = String> + '_ {
+    source.split("{%").skip(1).filter_map(|block| {
+        let block = block.split("%}").next()?;
+        let block = block.trim_start_matches(['-', '+', '~']).trim_start();
+        let (tag, rest) = block.split_once(char::is_whitespace)?;
+        if !matches!(tag, "include" | "extends" | "import") {
+            return None;
+        }
+        let rest = rest.trim_start().strip_prefix('"')?;
+        Some(rest.split('"').next()?.to_string())
+    })
+}
+
+/// Return true if `tokens[i..]` starts with a `::` path separator.
+fn is_path_sep(tokens: &[TokenTree], i: usize) -> bool {
+    matches!(
+        (tokens.get(i), tokens.get(i + 1)),
+        (Some(TokenTree::Punct(first)), Some(TokenTree::Punct(second)))
+            if first.as_char() == ':'
+                && first.spacing() == proc_macro2::Spacing::Joint
+                && second.as_char() == ':'
+    )
+}
+
+/// Return true if `tokens[i]` starts a path with a leading `::`, e.g. `::serde::Serialize`, as
+/// opposed to continuing a path like `Vec::<T>::new`.
+fn is_leading_path_sep(tokens: &[TokenTree], i: usize) -> bool {
+    is_path_sep(tokens, i)
+        && matches!(tokens.get(i + 2), Some(TokenTree::Ident(_)))
+        && match i.checked_sub(1).map(|prev| &tokens[prev]) {
+            None => true,
+            Some(TokenTree::Punct(punct)) => punct.as_char() != '>',
+            // a keyword can come right before a path, e.g. `x as ::dep::T` or `impl ::dep::Tr`
+            Some(TokenTree::Ident(ident)) => is_keyword(ident),
+            Some(TokenTree::Group(_)) => false,
+            Some(TokenTree::Literal(_)) => true,
+        }
+}
+
+/// Return true if the identifier is a keyword that can't be the first segment of a path. `crate`,
+/// `self`, `super` and `Self` can start paths, so they aren't included.
+fn is_keyword(ident: &syn::Ident) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "box", "break", "const", "continue", "dyn", "else", "enum",
+        "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "static", "struct", "trait", "type", "unsafe", "use", "where",
+        "while", "yield",
+    ];
+    KEYWORDS.iter().any(|keyword| ident == keyword)
+}
+
+/// Return true if the identifier at `tokens[i]` is not the start of a path, e.g. a method name
+/// after `.`, a lifetime after `'`, or a segment after a turbofish like `a::<T>::b`.
+fn continues_expr(tokens: &[TokenTree], i: usize) -> bool {
+    match i.checked_sub(1).map(|prev| &tokens[prev]) {
+        Some(TokenTree::Punct(punct)) if matches!(punct.as_char(), '.' | '\'') => true,
+        // a single `:` is a field or type separator, e.g. `"key": value`; only `::` continues a path
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
+            i >= 2 && is_path_sep(tokens, i - 2)
+        }
+        _ => false,
+    }
+}
+
+/// Return true if `tokens[i..]` is a macro call with a single-segment path, e.g. `vec![...]`.
+fn is_macro_call(tokens: &[TokenTree], i: usize) -> bool {
+    matches!(
+        (tokens.get(i), tokens.get(i + 1), tokens.get(i + 2)),
+        (Some(TokenTree::Ident(_)), Some(TokenTree::Punct(punct)), Some(TokenTree::Group(_)))
+            if punct.as_char() == '!'
+    ) && !continues_expr(tokens, i)
+}
+
+/// Starting at `tokens[i]`, skip any number of `::segment` path continuations. Returns the index of
+/// the first token after the path.
+fn skip_path(tokens: &[TokenTree], mut i: usize) -> usize {
+    while is_path_sep(tokens, i) && matches!(tokens.get(i + 2), Some(TokenTree::Ident(_))) {
+        i += 3;
+    }
+    i
 }
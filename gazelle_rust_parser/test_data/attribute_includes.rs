@@ -0,0 +1,9 @@
+#![doc = include_str!("README.md")]
+#![cfg_attr(docsrs, doc = include_str!("docs/crate.md"), doc = include_str!("docs/extra.md"))]
+#![cfg_attr(all(), cfg_attr(docsrs, doc = include_str!("docs/nested.md")))]
+
+#[cfg_attr(feature = "docs", doc = concat!(include_str!("docs/item.md"), "\n"))]
+pub fn f() {}
+
+#[cfg_attr(docsrs, doc = attr_value_dep::DOCS)]
+pub fn g() {}
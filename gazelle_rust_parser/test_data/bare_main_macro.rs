@@ -0,0 +1,6 @@
+use app_macros::main;
+
+// not a benchmark harness, since `main` doesn't come from iai or iai_callgrind
+main! {
+    fn run() {}
+}
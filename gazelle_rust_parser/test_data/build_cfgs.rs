@@ -0,0 +1,16 @@
+#[cfg(has_simd)]
+use simd_vector::Vector;
+#[cfg(not(has_simd))]
+use scalar_vector::Vector;
+
+#[cfg(backend = "gl")]
+use gl_backend as backend;
+#[cfg(backend = "vulkan")]
+use vulkan_backend as backend;
+
+// not enabled by the build script, so it can't be evaluated
+#[cfg(target_os = "linux")]
+use linux_only::Handle;
+
+#[cfg(has_unknown)]
+use unknown_crate::Thing;
@@ -0,0 +1,22 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build_script.rs");
+    println!("cargo::rerun-if-changed=templates");
+    println!("cargo:rerun-if-changed=./proto/../proto/events.proto");
+    println!("cargo:rerun-if-changed=/usr/include/zlib.h");
+    println!("cargo:rerun-if-env-changed=CC");
+    println!("cargo:rustc-cfg=has_simd");
+    println!("cargo::rustc-cfg=backend=\"gl\"");
+    println!("cargo::rustc-check-cfg=cfg(has_simd)");
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed={out_dir}/generated.rs");
+
+    cc::Build::new()
+        .file("native/shim.c")
+        .files(["native/util.c"])
+        .include("native/include")
+        .compile("shim");
+
+    prost_build::compile_protos(&["proto/api.proto"], &["proto/include"]).unwrap();
+    tonic_build::compile_protos("proto/service.proto").unwrap();
+}
@@ -0,0 +1,43 @@
+use include_dir::{include_dir, Dir};
+use rust_embed::RustEmbed;
+use sqlx::query;
+
+static ASSETS: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets");
+static MORE_ASSETS: Dir = include_dir::include_dir!("$CARGO_MANIFEST_DIR/more_assets");
+
+#[derive(RustEmbed)]
+#[folder = "static/"]
+struct Static;
+
+async fn count(pool: &sqlx::PgPool) {
+    // named through the use above
+    query!("SELECT COUNT(*) FROM users").fetch_one(pool).await;
+    sqlx::query_file!("queries/users.sql")
+        .fetch_all(pool)
+        .await;
+}
+
+// a local macro rather than `insta::glob!`, since insta isn't imported here
+macro_rules! glob {
+    ($pattern:expr) => {
+        $pattern
+    };
+}
+
+fn pattern() -> &'static str {
+    glob!("*.local")
+}
+
+// configured with a rule in the options
+fn fixture() -> &'static str {
+    load_fixture!("data/fixture.json")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn snapshots() {
+        insta::glob!("inputs/*.txt", |path| {});
+        insta::glob!("fixtures", "**/*.json", |path| {});
+    }
+}
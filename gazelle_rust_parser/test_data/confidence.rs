@@ -0,0 +1,32 @@
+extern crate extern_dep;
+
+use use_dep::Thing;
+
+fn definite() {
+    path_dep::call();
+    ::absolute_dep::call();
+    println!("{}", format_dep::describe());
+    assert_eq!(std::format!("{}", std_format_dep::value()), "");
+}
+
+fn macros() {
+    custom!(custom_dep::value());
+    json!({ "key": json_dep::value() });
+    // also found definitely elsewhere, so not heuristic
+    custom!(path_dep::call());
+}
+
+mod globbed {
+    use super::*;
+    #[gazelle::provides(provided)]
+    use other::*;
+
+    fn f() {
+        glob_dep::call();
+        ::glob_absolute_dep::call();
+    }
+
+    fn g() {
+        use inner_use_dep::Item;
+    }
+}
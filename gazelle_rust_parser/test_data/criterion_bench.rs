@@ -0,0 +1,8 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_sum(c: &mut Criterion) {
+    c.bench_function("sum", |b| b.iter(|| (0..black_box(100u64)).sum::<u64>()));
+}
+
+criterion_group!(benches, bench_sum);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+use std::fmt;
+
+#[cxx::bridge(namespace = "org::blobstore")]
+mod ffi {
+    struct BlobMetadata {
+        size: usize,
+        tags: Vec<String>,
+    }
+
+    extern "Rust" {
+        type MultiBuf;
+
+        fn next_chunk(buf: &mut MultiBuf) -> &[u8];
+    }
+
+    unsafe extern "C++" {
+        include!("blobstore/include/blobstore.h");
+        include!("blobstore/include/types.h");
+        // system headers aren't reported
+        include!(<memory>);
+
+        type BlobstoreClient;
+
+        fn new_blobstore_client() -> UniquePtr<BlobstoreClient>;
+        fn put(&self, parts: &mut MultiBuf) -> u64;
+    }
+}
+
+pub struct MultiBuf {
+    chunks: Vec<Vec<u8>>,
+    pos: usize,
+}
+
+pub fn next_chunk(buf: &mut MultiBuf) -> &[u8] {
+    let next = buf.chunks.get(buf.pos);
+    buf.pos += 1;
+    next.map_or(&[], Vec::as_slice)
+}
+
+impl fmt::Debug for MultiBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", log::Level::Info)
+    }
+}
+
+pub fn client() -> cxx::UniquePtr<ffi::BlobstoreClient> {
+    ffi::new_blobstore_client()
+}
@@ -0,0 +1,8 @@
+fn main() {
+    divan::main();
+}
+
+#[divan::bench(args = [1, 10, 100])]
+fn sum(n: u64) -> u64 {
+    (0..n).sum()
+}
@@ -0,0 +1,78 @@
+//! Crate-level docs:
+//!
+//! ```
+//! use crate_doc_dep::Thing;
+//! ```
+
+use regular_dep::Helper;
+
+/// A struct with a doctest:
+///
+/// ```rust
+/// # use hidden_dep::setup;
+/// # setup();
+/// let helper = doctests::Struct::new(regular_dep::Helper);
+/// assert_eq!(helper, struct_doc_dep::expected());
+/// ```
+///
+/// ```no_run
+/// fn main() {
+///     no_run_dep::run();
+/// }
+/// ```
+///
+/// ```ignore
+/// ignored_dep::nope();
+/// ```
+///
+/// ```compile_fail,E0425
+/// compile_fail_dep::nope();
+/// ```
+///
+/// ```text
+/// text_dep::nope();
+/// ```
+///
+/// ```E
+/// unknown_lang_dep::nope();
+/// ```
+///
+/// ~~~
+/// tilde_dep::run();
+/// ~~~
+pub struct Struct(Helper);
+
+impl Struct {
+    #[doc = "```"]
+    #[doc = "method_doc_dep::run();"]
+    #[doc = "```"]
+    pub fn new(helper: Helper) -> Self {
+        Self(helper)
+    }
+}
+
+pub enum Enum {
+    /**
+     * ```
+     * variant_doc_dep::run();
+     * ```
+     */
+    Variant,
+}
+
+pub mod inner {
+    /// ```
+    /// inner_mod_doc_dep::run();
+    /// ```
+    pub fn f() {}
+}
+
+#[cfg(feature = "disabled")]
+/// ```
+/// disabled_doc_dep::run();
+/// ```
+pub fn disabled() {}
+
+/// ```
+/// unterminated_dep::run();
+pub fn unterminated() {}
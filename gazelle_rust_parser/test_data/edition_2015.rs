@@ -0,0 +1,30 @@
+// In the 2015 edition, use paths are relative to the crate root, so they only name external crates
+// declared with `extern crate`. Other paths can still name crates in the extern prelude.
+
+extern crate extern_dep;
+extern crate renamed_dep as renamed;
+
+use extern_dep::Thing;
+use local::helper;
+use renamed::Other;
+
+mod local {
+    pub fn helper() {}
+}
+
+#[derive(serde::Serialize)]
+struct Config;
+
+fn main() {
+    ::local::helper();
+    local::helper();
+    prelude_dep::call();
+    println!("{}", extern_dep::value());
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test_extern_dep;
+
+    use test_extern_dep::check;
+}
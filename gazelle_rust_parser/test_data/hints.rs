@@ -0,0 +1,21 @@
+#![cfg_attr(not(test), no_std)]
+#![no_main]
+#![crate_type = "staticlib"]
+#![crate_name = "renamed"]
+
+use proc_macro::TokenStream;
+
+#[proc_macro_derive(Helper)]
+pub fn derive_helper(input: TokenStream) -> TokenStream {
+    input
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn exported() {}
+
+// not exported with a fixed name
+pub extern "C" fn callback() {}
+
+// exported, but with the Rust ABI
+#[no_mangle]
+pub fn rust_abi() {}
@@ -0,0 +1,11 @@
+use iai_callgrind::{library_benchmark, library_benchmark_group, main};
+
+#[library_benchmark]
+fn bench_sum() -> u64 {
+    (0..100u64).sum()
+}
+
+library_benchmark_group!(name = sums; benchmarks = bench_sum);
+
+// `main` is imported from iai_callgrind, so this expands to a main function
+main!(library_benchmark_groups = sums);
@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+include!("include_items.rs");
+
+fn lookup() -> HashMap<&'static str, u32> {
+    include!("include_expr.rs")
+}
+
+// included twice, but only parsed once
+include!("include_items.rs");
+
+// missing files are still reported
+include!("include_missing.rs");
+
+// absolute paths are outside of the package
+include!("/usr/share/generated/absolute.rs");
@@ -0,0 +1 @@
+expr_dep::build_map([("a", 1), ("b", 2)])
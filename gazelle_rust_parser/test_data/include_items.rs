@@ -0,0 +1,3 @@
+use items_dep::Table;
+
+static TABLE: Table = Table::new();
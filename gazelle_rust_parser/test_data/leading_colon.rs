@@ -0,0 +1,22 @@
+// Since the 2018 edition, a leading `::` always names an external crate, even when a mod of the
+// same name is in scope.
+
+mod shadowed {
+    pub fn local() {}
+}
+
+use ::use_dep::Thing;
+
+fn main() {
+    shadowed::local();
+    ::shadowed::external();
+    let x = ::absolute_dep::value();
+    println!("{}", ::macro_dep::describe(x));
+}
+
+#[macro_export]
+macro_rules! call_shadowed {
+    () => {
+        ::shadowed::external()
+    };
+}
@@ -0,0 +1,43 @@
+mod local {
+    pub fn value() -> u32 {
+        1
+    }
+}
+
+fn exprs() {
+    assert_eq!(local::value(), expr_dep::value(), "{}", format_dep::describe());
+}
+
+fn statements() {
+    my_macro! {
+        let x = stmt_dep::make();
+        use aliased_dep::Thing as Other;
+        Other::consume(x);
+    }
+}
+
+thread_local! {
+    static STATE: item_dep::State = item_dep::State::new();
+}
+
+fn unparseable() {
+    let value = json!({
+        "name": json_dep::name(),
+        "nested": [inner::value(), include_str!("json_data.txt")],
+        "data": ::absolute_dep::data(),
+    });
+    let page = html! {
+        <div class="x">{ html_dep::render(value) }</div>
+    };
+    // keywords right before `::` are not crates
+    custom!(=> a as ::cast_dep::T, impl ::trait_dep::Tr for Y ;;);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test() {
+        assert!(test_dep::check(super::local::value()));
+        assert!(local::value() > 0);
+    }
+}
@@ -0,0 +1,57 @@
+// `macro_rules!` bodies are analyzed by structure: matchers are skipped, metavariables are ignored,
+// and `$crate` refers to the current crate.
+
+mod local {
+    pub fn helper() {}
+}
+
+macro_rules! not_exported {
+    ($x:expr) => {
+        private_dep::consume($x);
+        local::helper();
+    };
+    ($x:expr, $($rest:tt)*) => {
+        $crate::not_exported!($x);
+        $(repeated_dep::consume($rest);)*
+        $x.method::<u8>();
+    };
+}
+
+#[macro_export]
+macro_rules! exported {
+    ($name:ident : $ty:ty, matcher_only::Path) => {
+        #[derive(::serde::Serialize)]
+        struct $name {
+            field: $ty,
+            other: ::absolute_dep::Other,
+        }
+
+        impl $name {
+            fn build() -> Self {
+                exported_dep::build::<$ty>::finish()
+            }
+        }
+
+        $crate::local::helper();
+        $name::method();
+    };
+}
+
+// keywords right before `::` are not crates
+#[macro_export]
+macro_rules! keywords {
+    ($x:expr) => {
+        $x as ::cast_dep::T
+    };
+    () => {
+        impl ::trait_dep::Tr for X {}
+    };
+}
+
+fn main() {
+    macro_rules! in_fn {
+        () => {
+            in_fn_dep::call()
+        };
+    }
+}
@@ -0,0 +1,31 @@
+use std::os::raw::{c_char, c_int};
+
+#[link(name = "ssl")]
+#[link(name = "crypto", kind = "static", modifiers = "+whole-archive")]
+extern "C" {
+    fn SSL_library_init() -> c_int;
+}
+
+#[cfg_attr(target_os = "macos", link(name = "CoreFoundation", kind = "framework"))]
+extern "C" {
+    fn CFRelease(cf: *const c_char);
+}
+
+#[cfg(feature = "zlib")]
+#[link(name = "z")]
+extern "C" {
+    fn zlibVersion() -> *const c_char;
+}
+
+#[link(wasm_import_module = "env")]
+extern "C" {
+    fn host_log(ptr: *const c_char);
+}
+
+#[cfg(test)]
+mod tests {
+    #[link(name = "test_only")]
+    extern "C" {
+        fn test_helper();
+    }
+}
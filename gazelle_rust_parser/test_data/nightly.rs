@@ -0,0 +1,8 @@
+#![feature(never_type, let_chains)]
+#![cfg_attr(nightly, feature(doc_cfg))]
+#![cfg_attr(feature = "simd", feature(portable_simd), allow(incomplete_features))]
+#![feature(never_type)]
+
+pub fn diverge() -> ! {
+    panic!()
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "renamed_serde", from = "wire_dep::Wire", into = "Vec<into_dep::Item>")]
+struct Config {
+    #[serde(with = "humantime_serde")]
+    timeout: std::time::Duration,
+    #[serde(deserialize_with = "chrono::serde::ts_seconds::deserialize")]
+    created: Timestamp,
+    // a local function, so only a heuristic import
+    #[serde(default = "default_port")]
+    port: u16,
+    // not a key that refers to code
+    #[serde(rename = "not_a_dep::path")]
+    renamed: String,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cfg_attr_dep::serialize"))]
+    conditional: String,
+    #[custom(helper = "custom_dep::helper")]
+    custom: String,
+}
+
+#[derive(clap::Parser)]
+struct Args {
+    #[arg(long, value_parser = value_parser_dep::parse_duration)]
+    duration: std::time::Duration,
+    #[clap(default_value_t = default_dep::DEFAULT)]
+    count: u32,
+}
+
+fn default_port() -> u16 {
+    8080
+}
@@ -0,0 +1,12 @@
+pub mod greeter {
+    tonic::include_proto!("helloworld.greeter.v1");
+}
+
+pub mod events {
+    include!(concat!(env!("OUT_DIR"), "/my.events.rs"));
+}
+
+pub mod legacy {
+    // not directly in OUT_DIR, so not generated by prost
+    include!(concat!(env!("OUT_DIR"), "/nested/legacy.rs"));
+}
@@ -0,0 +1,28 @@
+use pyo3::prelude::*;
+
+/// Adds two numbers.
+#[pyfunction]
+fn add(a: i64, b: i64) -> i64 {
+    a + b
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (values, scale = 1.0))]
+fn scaled_sum(values: Vec<f64>, scale: f64) -> f64 {
+    values.iter().sum::<f64>() * scale
+}
+
+#[pymodule]
+#[pyo3(name = "fast_math")]
+fn fast_math_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(add, m)?)?;
+    m.add_function(wrap_pyfunction!(scaled_sum, m)?)?;
+    m.add_submodule(&stats_module(m.py())?)?;
+    Ok(())
+}
+
+// a submodule, which doesn't name the extension module
+#[pymodule(name = "stats")]
+fn stats_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("VERSION", env!("CARGO_PKG_VERSION"))
+}
@@ -0,0 +1,19 @@
+use runfiles::{rlocation, Runfiles};
+
+fn main() {
+    let r = Runfiles::create().unwrap();
+    let config = rlocation!(r, "_main/config/app.toml").unwrap();
+    let schema = r.rlocation("_main/schemas/v1.json");
+    let other = Runfiles::rlocation(&r, "other_repo/data/file.bin");
+    let dynamic = r.rlocation(format!("_main/{}", "x"));
+    println!("{:?}", (config, schema, other, dynamic));
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn golden() {
+        let r = runfiles::Runfiles::create().unwrap();
+        assert!(runfiles::rlocation!(r, "_main/tests/golden.txt").is_some());
+    }
+}
@@ -0,0 +1,42 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// compile data rather than runtime data
+static README: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"));
+
+// not passed to a fs API
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/unused");
+
+fn config_dir() -> PathBuf {
+    // a directory, so it is reported as a glob
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("templates")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        let json = fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("fixtures/x.json"),
+        )
+        .unwrap();
+        let text = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/input.txt"));
+        assert_eq!(
+            fs::read(&Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/expected.bin")).unwrap(),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn not_resolvable() {
+        let name = "x.json";
+        let _ = Path::new(env!("CARGO_MANIFEST_DIR")).join(name);
+        let _ = Path::new(env!("CARGO_MANIFEST_DIR")).join("/etc/passwd");
+        let _ = Path::new(env!("CARGO_MANIFEST_DIR")).join("../outside.txt");
+        let _ = Path::new(env!("OUT_DIR")).join("generated.rs");
+    }
+}
@@ -0,0 +1,34 @@
+pub fn render() -> String {
+    String::from("hello")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use insta::{assert_debug_snapshot, assert_snapshot};
+
+    #[test]
+    fn file_snapshot() {
+        assert_snapshot!(render());
+        insta::assert_yaml_snapshot!(vec![1, 2]);
+    }
+
+    #[test]
+    fn inline_snapshot() {
+        assert_debug_snapshot!(vec![1, 2], @"[1, 2]");
+    }
+
+    #[test]
+    fn custom_path() {
+        insta::with_settings!({snapshot_path => "golden", prepend_module_to_snapshot => false}, {
+            assert_snapshot!(serde_json::to_string(&1).unwrap());
+        });
+    }
+
+    #[test]
+    fn custom_settings() {
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_path("../outside");
+        settings.bind(|| assert_snapshot!(render()));
+    }
+}
@@ -0,0 +1,17 @@
+use askama::Template;
+
+#[derive(Template)]
+#[template(path = "hello.html")]
+struct Hello<'a> {
+    name: &'a str,
+}
+
+// inline sources can include templates too
+#[derive(Template)]
+#[template(source = "{% include \"partials/nav.html\" %}", ext = "html")]
+struct Nav;
+
+// missing templates are skipped
+#[derive(Template)]
+#[template(path = "missing.html")]
+struct Missing;
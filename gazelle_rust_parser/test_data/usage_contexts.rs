@@ -0,0 +1,17 @@
+use use_only::Thing;
+use derive_dep::Derived;
+
+#[derive(Debug, derive_dep::Derived, serde::Serialize)]
+#[attr_dep::attribute]
+#[cfg_attr(feature = "x", cfg_attr_dep::attribute)]
+struct Foo {
+    #[serde(with = "chrono")]
+    field: path_dep::Type,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    macro_dep::bang!(inner_dep::value());
+    tokio::spawn(async {});
+    let x: mixed_dep::Thing = mixed_dep::make!();
+}
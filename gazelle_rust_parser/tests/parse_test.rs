@@ -2,13 +2,38 @@ use std::collections::HashSet;
 use std::error::Error;
 use std::path::PathBuf;
 
+use gazelle_rust_parser::{Hints, UsageContext};
+
+#[derive(Default)]
 struct TestCase {
     filename: &'static str,
     enabled_features: Vec<&'static str>,
+    edition: Option<&'static str>,
+    path_attributes: Vec<&'static str>,
+    compile_data_rules: Vec<&'static str>,
+    enabled_cfgs: Vec<&'static str>,
+    build_script: bool,
     expected_imports: Vec<&'static str>,
     expected_test_imports: Vec<&'static str>,
     expected_extern_mods: Vec<&'static str>,
     expected_compile_data: Vec<&'static str>,
+    expected_data: Vec<&'static str>,
+    expected_runfiles: Vec<&'static str>,
+    expected_trybuild_patterns: Vec<&'static str>,
+    expected_build_script_data: Vec<&'static str>,
+    expected_build_script_cfgs: Vec<&'static str>,
+    /// Pairs of name and kind
+    expected_native_libs: Vec<(&'static str, &'static str)>,
+    expected_proto_packages: Vec<&'static str>,
+    expected_cxx_includes: Vec<&'static str>,
+    expected_included_srcs: Vec<&'static str>,
+    expected_exported_macro_imports: Vec<&'static str>,
+    expected_heuristic_imports: Vec<&'static str>,
+    /// Only the crates listed here are checked
+    expected_crate_usages: Vec<(&'static str, Vec<UsageContext>)>,
+    /// Only checked if set
+    expected_hints: Option<Hints>,
+    expected_doc_test_imports: Vec<&'static str>,
 }
 
 lazy_static::lazy_static! {
@@ -50,6 +75,20 @@ lazy_static::lazy_static! {
                 "file1.txt",
                 "file2.txt",
             ],
+            expected_heuristic_imports: vec![
+                "gazelle",
+                "test_args_1",
+                "test_attribute_1",
+                "test_cfg_attr_derive",
+                "test_cfg_attr_macro",
+                "test_cfg_attr_macro_on_fn",
+                "test_cfg_attr_macro_on_impl",
+                "test_derive_1",
+                "test_inner_1",
+                "test_inner_mod_2",
+                "test_ret_1",
+            ],
+            ..Default::default()
         },
         TestCase {
             filename: "test_only.rs",
@@ -67,6 +106,7 @@ lazy_static::lazy_static! {
             ],
             expected_extern_mods: vec![],
             expected_compile_data: vec![],
+            ..Default::default()
         },
         TestCase {
             filename: "early_mod.rs",
@@ -75,6 +115,7 @@ lazy_static::lazy_static! {
             expected_test_imports: vec![],
             expected_extern_mods: vec![],
             expected_compile_data: vec![],
+            ..Default::default()
         },
         TestCase {
             filename: "features.rs",
@@ -91,6 +132,7 @@ lazy_static::lazy_static! {
                 "extern_mod_2",
             ],
             expected_compile_data: vec![],
+            ..Default::default()
         },
         TestCase {
             filename: "macros.rs",
@@ -114,6 +156,109 @@ lazy_static::lazy_static! {
                 "file6.txt",
                 "file7.txt",
             ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "macro_inputs.rs",
+            expected_imports: vec![
+                "expr_dep",
+                "format_dep",
+                "stmt_dep",
+                "aliased_dep",
+                "item_dep",
+                "json_dep",
+                "inner",
+                "absolute_dep",
+                "html_dep",
+                "cast_dep",
+                "trait_dep",
+            ],
+            expected_test_imports: vec!["test_dep"],
+            expected_compile_data: vec!["json_data.txt"],
+            expected_heuristic_imports: vec![
+                "stmt_dep",
+                "aliased_dep",
+                "item_dep",
+                "json_dep",
+                "inner",
+                "absolute_dep",
+                "html_dep",
+                "cast_dep",
+                "trait_dep",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "confidence.rs",
+            expected_imports: vec![
+                "extern_dep",
+                "gazelle",
+                "std",
+                "use_dep",
+                "path_dep",
+                "absolute_dep",
+                "format_dep",
+                "std_format_dep",
+                "custom_dep",
+                "json_dep",
+                "other",
+                "glob_dep",
+                "glob_absolute_dep",
+                "inner_use_dep",
+            ],
+            expected_heuristic_imports: vec!["custom_dep", "json_dep", "glob_dep", "gazelle"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "usage_contexts.rs",
+            expected_imports: vec![
+                "use_only",
+                "derive_dep",
+                "serde",
+                "attr_dep",
+                "cfg_attr_dep",
+                "path_dep",
+                "chrono",
+                "tokio",
+                "macro_dep",
+                "inner_dep",
+                "mixed_dep",
+            ],
+            expected_heuristic_imports: vec!["inner_dep", "chrono"],
+            expected_crate_usages: vec![
+                ("use_only", vec![UsageContext::Use]),
+                ("derive_dep", vec![UsageContext::Use, UsageContext::Derive]),
+                ("serde", vec![UsageContext::Derive]),
+                ("attr_dep", vec![UsageContext::Attribute]),
+                ("cfg_attr_dep", vec![UsageContext::Attribute]),
+                ("path_dep", vec![UsageContext::Path]),
+                (
+                    "tokio",
+                    vec![UsageContext::Path, UsageContext::Attribute],
+                ),
+                ("macro_dep", vec![UsageContext::Macro]),
+                ("inner_dep", vec![UsageContext::Path]),
+                ("mixed_dep", vec![UsageContext::Path, UsageContext::Macro]),
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "edition_2015.rs",
+            edition: Some("2015"),
+            expected_imports: vec!["extern_dep", "renamed_dep", "serde", "prelude_dep"],
+            expected_test_imports: vec!["test_extern_dep"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "leading_colon.rs",
+            expected_imports: vec!["shadowed", "absolute_dep", "use_dep", "macro_dep"],
+            expected_exported_macro_imports: vec!["shadowed"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "leading_colon.rs",
+            edition: Some("2015"),
+            ..Default::default()
         },
         TestCase {
             filename: "alternative_test_attributes.rs",
@@ -135,6 +280,7 @@ lazy_static::lazy_static! {
             ],
             expected_extern_mods: vec![],
             expected_compile_data: vec![],
+            ..Default::default()
         },
         TestCase {
             filename: "provides.rs",
@@ -143,13 +289,328 @@ lazy_static::lazy_static! {
             expected_test_imports: vec![],
             expected_extern_mods: vec![],
             expected_compile_data: vec![],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "macro_rules.rs",
+            expected_imports: vec![
+                "private_dep",
+                "repeated_dep",
+                "serde",
+                "absolute_dep",
+                "exported_dep",
+                "in_fn_dep",
+                "cast_dep",
+                "trait_dep",
+            ],
+            expected_exported_macro_imports: vec![
+                "serde",
+                "absolute_dep",
+                "exported_dep",
+                "cast_dep",
+                "trait_dep",
+            ],
+            expected_heuristic_imports: vec![
+                "private_dep",
+                "repeated_dep",
+                "exported_dep",
+                "in_fn_dep",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "hints.rs",
+            expected_imports: vec!["proc_macro"],
+            expected_hints: Some(Hints {
+                has_proc_macro_derive: true,
+                has_c_exports: true,
+                no_std: true,
+                no_main: true,
+                crate_types: vec!["staticlib".to_string()],
+                crate_name: Some("renamed".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "nightly.rs",
+            expected_hints: Some(Hints {
+                nightly_features: vec![
+                    "never_type".to_string(),
+                    "let_chains".to_string(),
+                    "doc_cfg".to_string(),
+                    "portable_simd".to_string(),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "doctests.rs",
+            expected_imports: vec!["regular_dep"],
+            expected_doc_test_imports: vec![
+                "crate_doc_dep",
+                "hidden_dep",
+                "doctests",
+                "regular_dep",
+                "struct_doc_dep",
+                "no_run_dep",
+                "tilde_dep",
+                "method_doc_dep",
+                "variant_doc_dep",
+                "inner_mod_doc_dep",
+                "unterminated_dep",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "attribute_includes.rs",
+            expected_imports: vec!["attr_value_dep"],
+            expected_compile_data: vec![
+                "README.md",
+                "docs/crate.md",
+                "docs/extra.md",
+                "docs/nested.md",
+                "docs/item.md",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "include.rs",
+            expected_imports: vec!["std", "items_dep", "expr_dep"],
+            expected_included_srcs: vec![
+                "include_items.rs",
+                "include_expr.rs",
+                "include_missing.rs",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "templates.rs",
+            expected_imports: vec!["askama"],
+            expected_compile_data: vec![
+                "askama.toml",
+                "templates/hello.html",
+                "layouts/base.html",
+                "templates/macros.html",
+                "templates/partials/greeting.html",
+                "templates/partials/wave.html",
+                "templates/partials/nav.html",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "path_attributes.rs",
+            path_attributes: vec!["custom.helper"],
+            expected_imports: vec![
+                "serde",
+                "renamed_serde",
+                "wire_dep",
+                "into_dep",
+                "humantime_serde",
+                "std",
+                "chrono",
+                "default_port",
+                "cfg_attr_dep",
+                "custom_dep",
+                "clap",
+                "value_parser_dep",
+                "default_dep",
+            ],
+            expected_heuristic_imports: vec!["renamed_serde", "humantime_serde", "default_port"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "compile_data_rules.rs",
+            compile_data_rules: vec!["load_fixture!=source_file"],
+            expected_imports: vec!["include_dir", "rust_embed", "sqlx"],
+            expected_test_imports: vec!["insta"],
+            expected_compile_data: vec![
+                "assets/**",
+                "more_assets/**",
+                "static/**",
+                ".sqlx/query-*.json",
+                "queries/users.sql",
+                "data/fixture.json",
+                "inputs/*.txt",
+                "fixtures/**/*.json",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "runtime_data.rs",
+            expected_imports: vec!["std"],
+            expected_compile_data: vec!["README.md"],
+            expected_data: vec![
+                "templates/**",
+                "tests/fixtures/x.json",
+                "testdata/input.txt",
+                "testdata/expected.bin",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "runfiles.rs",
+            expected_imports: vec!["runfiles"],
+            expected_runfiles: vec![
+                "_main/config/app.toml",
+                "_main/schemas/v1.json",
+                "other_repo/data/file.bin",
+                "_main/tests/golden.txt",
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "snapshots.rs",
+            expected_test_imports: vec!["insta", "serde_json"],
+            // inside the input of an assertion macro
+            expected_heuristic_imports: vec!["serde_json"],
+            expected_hints: Some(Hints {
+                has_test: true,
+                has_inline_snapshot: true,
+                has_file_snapshot: true,
+                snapshot_dirs: vec!["golden".to_string(), "snapshots".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "trybuild.rs",
+            expected_test_imports: vec!["trybuild"],
+            expected_trybuild_patterns: vec!["tests/ui/fail/*.rs", "tests/ui/pass/*.rs"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "build_script.rs",
+            build_script: true,
+            expected_imports: vec!["cc", "prost_build", "std", "tonic_build"],
+            expected_build_script_data: vec![
+                "build_script.rs",
+                "native/include/**",
+                "native/shim.c",
+                "native/util.c",
+                "proto/api.proto",
+                "proto/events.proto",
+                "proto/include/**",
+                "proto/service.proto",
+                "templates/**",
+            ],
+            expected_build_script_cfgs: vec!["has_simd", "backend=\"gl\""],
+            ..Default::default()
+        },
+        TestCase {
+            // only build scripts are scanned for the files that they read and the cfgs that they
+            // enable
+            filename: "build_script.rs",
+            expected_imports: vec!["cc", "prost_build", "std", "tonic_build"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "build_cfgs.rs",
+            enabled_cfgs: vec!["has_simd", "backend=\"gl\""],
+            expected_imports: vec!["simd_vector", "gl_backend", "linux_only"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "native_libs.rs",
+            expected_imports: vec!["std"],
+            expected_native_libs: vec![
+                ("ssl", "dylib"),
+                ("crypto", "static"),
+                ("CoreFoundation", "framework"),
+            ],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "proto_packages.rs",
+            expected_imports: vec!["tonic"],
+            expected_proto_packages: vec!["helloworld.greeter.v1", "my.events"],
+            ..Default::default()
+        },
+        TestCase {
+            filename: "cxx_bridge.rs",
+            expected_imports: vec!["cxx", "log", "std"],
+            // the headers aren't Rust sources
+            expected_cxx_includes: vec![
+                "blobstore/include/blobstore.h",
+                "blobstore/include/types.h",
+            ],
+            expected_hints: Some(Hints {
+                has_cxx_bridge: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "pyo3_module.rs",
+            expected_imports: vec!["pyo3"],
+            expected_hints: Some(Hints {
+                python_module: Some("fast_math".to_string()),
+                has_pyfunction: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "wasm_bindgen.rs",
+            expected_imports: vec!["wasm_bindgen"],
+            expected_hints: Some(Hints {
+                has_wasm_bindgen_exports: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            // only imports from JavaScript, so this isn't meant to be loaded by JavaScript
+            filename: "wasm_bindgen_imports.rs",
+            expected_imports: vec!["wasm_bindgen"],
+            expected_test_imports: vec!["wasm_bindgen_test"],
+            expected_hints: Some(Hints::default()),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "criterion_bench.rs",
+            expected_imports: vec!["criterion"],
+            // criterion_main! expands to a main function
+            expected_hints: Some(Hints {
+                has_main: true,
+                has_bench: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "divan_bench.rs",
+            expected_imports: vec!["divan"],
+            expected_hints: Some(Hints {
+                has_main: true,
+                has_bench: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "iai_bench.rs",
+            expected_imports: vec!["iai_callgrind"],
+            expected_hints: Some(Hints {
+                has_main: true,
+                has_bench: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        TestCase {
+            filename: "bare_main_macro.rs",
+            expected_imports: vec!["app_macros"],
+            expected_hints: Some(Hints::default()),
+            ..Default::default()
         },
     ];
 }
 
-fn assert_eq_vecs(actual: &[String], expected: &[String], msg: &str) {
-    let actual_set: HashSet<_> = actual.iter().collect();
-    let expected_set: HashSet<_> = expected.iter().collect();
+fn assert_eq_vecs(actual: &[String], expected: &[&str], msg: &str) {
+    let actual_set: HashSet<_> = actual.iter().map(String::as_str).collect();
+    let expected_set: HashSet<_> = expected.iter().copied().collect();
     if actual_set != expected_set {
         let mut only_actual: Vec<_> = actual_set.difference(&expected_set).collect();
         only_actual.sort();
@@ -189,50 +650,130 @@ fn parse_test() -> Result<(), Box<dyn Error>> {
     for test_case in &*TEST_CASES {
         let mut file = dir.clone();
         file.push(test_case.filename);
-        let enabled_features: Vec<String> = test_case
-            .enabled_features
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+        let options = gazelle_rust_parser::ParseOptions {
+            enabled_features: test_case
+                .enabled_features
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            edition: test_case
+                .edition
+                .map_or(Ok(Default::default()), str::parse)?,
+            path_attributes: test_case
+                .path_attributes
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            compile_data_rules: test_case
+                .compile_data_rules
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            enabled_cfgs: test_case
+                .enabled_cfgs
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            build_script: test_case.build_script,
+        };
 
-        let rust_imports =
-            gazelle_rust_parser::parse_imports(file, PathBuf::new(), &enabled_features)?;
+        let rust_imports = gazelle_rust_parser::parse_imports(file, PathBuf::new(), &options)?;
         assert_eq_vecs(
             &rust_imports.imports,
-            &test_case
-                .expected_imports
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<_>>(),
+            &test_case.expected_imports,
             "imports",
         );
         assert_eq_vecs(
             &rust_imports.test_imports,
-            &test_case
-                .expected_test_imports
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<_>>(),
+            &test_case.expected_test_imports,
             "test_imports",
         );
         assert_eq_vecs(
             &rust_imports.extern_mods,
-            &test_case
-                .expected_extern_mods
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<_>>(),
+            &test_case.expected_extern_mods,
             "extern_modes",
         );
         assert_eq_vecs(
             &rust_imports.compile_data,
-            &test_case
-                .expected_compile_data
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<_>>(),
+            &test_case.expected_compile_data,
             "compile_data",
         );
+        assert_eq_vecs(&rust_imports.data, &test_case.expected_data, "data");
+        assert_eq_vecs(
+            &rust_imports.runfiles,
+            &test_case.expected_runfiles,
+            "runfiles",
+        );
+        assert_eq_vecs(
+            &rust_imports.trybuild_patterns,
+            &test_case.expected_trybuild_patterns,
+            "trybuild_patterns",
+        );
+        assert_eq_vecs(
+            &rust_imports.build_script_data,
+            &test_case.expected_build_script_data,
+            "build_script_data",
+        );
+        assert_eq_vecs(
+            &rust_imports.build_script_cfgs,
+            &test_case.expected_build_script_cfgs,
+            "build_script_cfgs",
+        );
+        let native_libs: Vec<_> = rust_imports
+            .native_libs
+            .iter()
+            .map(|lib| (lib.name.as_str(), lib.kind.as_str()))
+            .collect();
+        let mut expected_native_libs = test_case.expected_native_libs.clone();
+        expected_native_libs.sort();
+        assert_eq!(native_libs, expected_native_libs, "native_libs");
+        assert_eq_vecs(
+            &rust_imports.proto_packages,
+            &test_case.expected_proto_packages,
+            "proto_packages",
+        );
+        assert_eq_vecs(
+            &rust_imports.cxx_includes,
+            &test_case.expected_cxx_includes,
+            "cxx_includes",
+        );
+        assert_eq_vecs(
+            &rust_imports.included_srcs,
+            &test_case.expected_included_srcs,
+            "included_srcs",
+        );
+        assert_eq_vecs(
+            &rust_imports.exported_macro_imports,
+            &test_case.expected_exported_macro_imports,
+            "exported_macro_imports",
+        );
+        assert_eq_vecs(
+            &rust_imports.heuristic_imports,
+            &test_case.expected_heuristic_imports,
+            "heuristic_imports",
+        );
+
+        assert_eq_vecs(
+            &rust_imports.doc_test_imports,
+            &test_case.expected_doc_test_imports,
+            "doc_test_imports",
+        );
+
+        for (crate_name, expected_contexts) in &test_case.expected_crate_usages {
+            let usage = rust_imports
+                .crate_usages
+                .iter()
+                .find(|usage| usage.crate_name == *crate_name);
+            assert_eq!(
+                usage.map(|usage| &usage.contexts),
+                Some(expected_contexts),
+                "crate_usages for {crate_name}",
+            );
+        }
+
+        if let Some(expected_hints) = &test_case.expected_hints {
+            assert_eq!(&rust_imports.hints, expected_hints, "hints");
+        }
     }
 
     Ok(())
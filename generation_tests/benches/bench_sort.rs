@@ -0,0 +1,14 @@
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+#[bench]
+fn sort_reversed(b: &mut Bencher) {
+    b.iter(|| {
+        let mut v: Vec<u32> = (0..1000).rev().collect();
+        v.sort();
+        v
+    });
+}
@@ -0,0 +1,15 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn fib(n: u64) -> u64 {
+    match n {
+        0 | 1 => n,
+        n => fib(n - 1) + fib(n - 2),
+    }
+}
+
+fn bench_fib(c: &mut Criterion) {
+    c.bench_function("fib 20", |b| b.iter(|| fib(20)));
+}
+
+criterion_group!(benches, bench_fib);
+criterion_main!(benches);
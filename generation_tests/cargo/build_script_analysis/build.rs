@@ -0,0 +1,13 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=native/include");
+
+    cc::Build::new()
+        .file("native/shim.c")
+        .include("native/include")
+        .compile("shim");
+
+    if std::env::var("CARGO_CFG_TARGET_FEATURE").is_ok_and(|features| features.contains("avx2")) {
+        println!("cargo:rustc-cfg=has_simd");
+    }
+}
@@ -0,0 +1 @@
+int shim_add(int a, int b);
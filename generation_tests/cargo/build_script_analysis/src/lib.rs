@@ -0,0 +1,12 @@
+#[cfg(has_simd)]
+mod simd;
+#[cfg(not(has_simd))]
+mod scalar;
+
+extern "C" {
+    fn shim_add(a: i32, b: i32) -> i32;
+}
+
+pub fn add(a: i32, b: i32) -> i32 {
+    unsafe { shim_add(a, b) }
+}
@@ -0,0 +1,3 @@
+pub fn sum(values: &[f32]) -> f32 {
+    values.iter().fold(0.0, |acc, v| acc + v)
+}
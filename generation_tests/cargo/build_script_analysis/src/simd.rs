@@ -0,0 +1,3 @@
+pub fn sum(values: &[f32]) -> f32 {
+    values.iter().sum()
+}
@@ -0,0 +1,22 @@
+macro_rules! embed_dir {
+    ($dir:literal) => {
+        &[]
+    };
+}
+
+macro_rules! fixture {
+    ($path:literal) => {
+        include_str!($path)
+    };
+}
+
+pub static ASSETS: &[&str] = embed_dir!("$CARGO_MANIFEST_DIR/assets");
+pub static STATIC: &[&str] = embed_dir!("static/");
+
+pub fn schema() -> &'static str {
+    fixture!("fixtures/schema.json")
+}
+
+pub fn readme() -> &'static str {
+    include_str!("../README.md")
+}
@@ -0,0 +1,6 @@
+/// Makes a thing.
+///
+/// ```
+/// let x = doc_tests::make();
+/// ```
+pub fn make() {}
@@ -0,0 +1,17 @@
+#pragma once
+#include "rust/cxx.h"
+#include <memory>
+
+namespace org {
+namespace blobstore {
+
+class BlobstoreClient {
+public:
+  BlobstoreClient();
+  uint64_t put(rust::Str blob) const;
+};
+
+std::unique_ptr<BlobstoreClient> new_blobstore_client();
+
+} // namespace blobstore
+} // namespace org
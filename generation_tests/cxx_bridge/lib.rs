@@ -0,0 +1,14 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("rust/cxx.h");
+    }
+
+    extern "Rust" {
+        fn greeting() -> String;
+    }
+}
+
+pub fn greeting() -> String {
+    "hello".to_string()
+}
@@ -0,0 +1,17 @@
+#[cxx::bridge(namespace = "org::blobstore")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("demo/include/blobstore.h");
+
+        type BlobstoreClient;
+
+        fn new_blobstore_client() -> UniquePtr<BlobstoreClient>;
+        fn put(&self, blob: &str) -> u64;
+    }
+}
+
+fn main() {
+    let client = ffi::new_blobstore_client();
+    let blobid = client.put("hello");
+    println!("blobid = {blobid}");
+}
@@ -0,0 +1,9 @@
+/// Makes a thing.
+///
+/// ```
+/// let x = lib::make();
+/// helper::check(x);
+/// ```
+pub fn make() {
+    common::thing();
+}
@@ -0,0 +1,13 @@
+extern crate helper;
+
+mod other {
+    pub fn call() {}
+}
+
+use other::call;
+
+pub fn legacy() {
+    call();
+    other::call();
+    helper::value();
+}
@@ -0,0 +1,9 @@
+mod helper {
+    pub fn value() -> u32 {
+        2
+    }
+}
+
+pub fn shadow() -> u32 {
+    helper::value() + ::helper::value()
+}
@@ -0,0 +1,3 @@
+pub fn lib() -> u32 {
+    custom!(helper::value(), unknown_dep::value())
+}
@@ -0,0 +1,6 @@
+use proc_macro::TokenStream;
+
+#[proc_macro_derive(Helper)]
+pub fn derive_helper(input: TokenStream) -> TokenStream {
+    input
+}
@@ -0,0 +1,4 @@
+#![crate_type = "cdylib"]
+#![crate_name = "explicit_ffi"]
+
+pub fn not_exported() {}
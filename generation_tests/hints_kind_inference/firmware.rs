@@ -0,0 +1,9 @@
+#![no_std]
+
+#[unsafe(no_mangle)]
+pub extern "C" fn blink() {}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,5 @@
+include!("tables.rs");
+
+pub fn lookup() -> &'static helper::Table {
+    &TABLE
+}
@@ -0,0 +1 @@
+static TABLE: helper::Table = helper::Table;
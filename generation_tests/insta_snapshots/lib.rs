@@ -0,0 +1,13 @@
+pub fn render(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+
+    #[test]
+    fn render_world() {
+        insta::assert_snapshot!(render("world"));
+    }
+}
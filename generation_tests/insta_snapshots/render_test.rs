@@ -0,0 +1,7 @@
+use insta::assert_snapshot;
+use lib::render;
+
+#[test]
+fn render_inline() {
+    assert_snapshot!(render("inline"), @"Hello, inline!");
+}
@@ -0,0 +1,24 @@
+use std::os::raw::c_int;
+
+#[link(name = "ssl")]
+extern "C" {
+    fn OPENSSL_init_ssl(opts: u64, settings: *const u8) -> c_int;
+}
+
+#[link(name = "shim", kind = "static")]
+#[link(name = "m")]
+extern "C" {
+    fn shim_version() -> c_int;
+}
+
+pub fn init() -> bool {
+    unsafe { OPENSSL_init_ssl(0, std::ptr::null()) == 1 && shim_version() > 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn initializes() {
+        assert!(super::init());
+    }
+}
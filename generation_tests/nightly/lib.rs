@@ -0,0 +1,14 @@
+#![feature(never_type)]
+
+pub fn fail(msg: &str) -> ! {
+    panic!("{msg}")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[should_panic]
+    fn fails() {
+        super::fail("oops");
+    }
+}
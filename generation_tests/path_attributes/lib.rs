@@ -0,0 +1,6 @@
+pub struct Config {
+    #[serde(with = "helper")]
+    pub timeout: u64,
+    #[custom(helper = "other::f")]
+    pub name: String,
+}
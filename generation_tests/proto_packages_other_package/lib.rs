@@ -0,0 +1,9 @@
+pub mod greeter {
+    tonic::include_proto!("helloworld.greeter.v1");
+}
+
+pub fn request(name: &str) -> greeter::HelloRequest {
+    greeter::HelloRequest {
+        name: name.to_string(),
+    }
+}
@@ -0,0 +1,8 @@
+use greeter_rust_proto::Greeter;
+use types_rust_proto::HelloRequest;
+
+pub fn request(name: &str) -> HelloRequest {
+    let mut request = HelloRequest::new();
+    request.set_name(name.to_string());
+    request
+}
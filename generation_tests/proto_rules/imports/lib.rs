@@ -0,0 +1,5 @@
+use user_proto::user::User;
+
+pub fn user() -> User {
+    User::default()
+}
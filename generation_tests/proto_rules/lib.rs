@@ -0,0 +1,6 @@
+use bar_proto::bar::Bar;
+use foo_proto::foo::Foo;
+
+pub fn make() -> (Foo, Bar) {
+    (Foo {}, Bar {})
+}
@@ -0,0 +1,9 @@
+use pyo3::prelude::*;
+
+#[pymodule]
+mod greeter {
+    #[pyfunction]
+    fn greet(name: &str) -> String {
+        format!("hello, {name}")
+    }
+}
@@ -0,0 +1,10 @@
+use runfiles::{rlocation, Runfiles};
+
+fn main() {
+    let r = Runfiles::create().unwrap();
+    let config = rlocation!(r, "_main/data/config.toml").unwrap();
+    let schema = r.rlocation("_main/schemas/v1.json");
+    // files in other repos can't be resolved
+    let other = r.rlocation("other_repo/file.bin");
+    println!("{:?}", (config, schema, other));
+}
@@ -0,0 +1,10 @@
+use std::fs;
+use std::path::PathBuf;
+
+#[test]
+fn parses_all_fixtures() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    for entry in fs::read_dir(dir).unwrap() {
+        lib::parse(&fs::read_to_string(entry.unwrap().path()).unwrap());
+    }
+}
@@ -0,0 +1,17 @@
+pub fn parse(input: &str) -> usize {
+    input.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn parses_fixture() {
+        let input =
+            fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/input.txt"))
+                .unwrap();
+        assert_eq!(super::parse(&input), 6);
+    }
+}
@@ -0,0 +1,5 @@
+use lib::greet;
+
+fn main() {
+    assert_eq!(greet("world"), "Hello, world!");
+}
@@ -0,0 +1,5 @@
+use lib::greet;
+
+fn main() {
+    greet();
+}
@@ -0,0 +1,6 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/*_fail.rs");
+    t.pass("ui/*_pass.rs");
+}
@@ -0,0 +1,12 @@
+// resolved as a proc_macro crate
+#[derive(derive_helper::Helper)]
+pub struct Foo {
+    pub bar: serde::de::IgnoredAny,
+}
+
+#[derive(serde::Serialize)]
+pub struct Baz;
+
+pub fn run() {
+    runtime_helper::run();
+}
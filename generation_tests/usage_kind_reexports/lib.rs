@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+#[derive(Serialize, serde::Deserialize)]
+pub struct Config;
+
+#[tokio::main]
+pub async fn run() {}
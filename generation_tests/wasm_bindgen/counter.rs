@@ -0,0 +1,19 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Counter {
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl Counter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Counter {
+        Counter { count: 0 }
+    }
+
+    pub fn increment(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
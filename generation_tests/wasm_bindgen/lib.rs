@@ -0,0 +1,16 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    fn alert(s: &str);
+}
+
+#[wasm_bindgen(start)]
+fn start() {
+    alert("loaded");
+}
+
+#[wasm_bindgen]
+pub fn greet(name: &str) -> String {
+    format!("hello, {name}")
+}
@@ -1,6 +1,7 @@
 package rust_language
 
 import (
+	"io/fs"
 	"log"
 	"os"
 	"path"
@@ -12,6 +13,7 @@ import (
 	"github.com/bazelbuild/bazel-gazelle/label"
 	"github.com/bazelbuild/bazel-gazelle/language"
 	"github.com/bazelbuild/bazel-gazelle/rule"
+	bzl "github.com/bazelbuild/buildtools/build"
 
 	pb "github.com/calsign/gazelle_rust/proto"
 )
@@ -88,17 +90,62 @@ func (l *rustLang) isTestFilename(filename string) bool {
 	return strings.HasSuffix(filename, "_test.rs") || strings.HasPrefix(filename, "test_")
 }
 
+func (l *rustLang) isBenchDir(dirname *string) bool {
+	return dirname != nil && (*dirname == "bench" || *dirname == "benches")
+}
+
+func (l *rustLang) isBenchFilename(filename string) bool {
+	return strings.HasSuffix(filename, "_bench.rs") || strings.HasPrefix(filename, "bench_")
+}
+
+// Rule kinds corresponding to values of #![crate_type].
+var crateTypeKinds = map[string]string{
+	"bin":        "rust_binary",
+	"lib":        "rust_library",
+	"rlib":       "rust_library",
+	"proc-macro": "rust_proc_macro",
+	"cdylib":     "rust_shared_library",
+	"dylib":      "rust_shared_library",
+	"staticlib":  "rust_static_library",
+}
+
 // Infer the default kind for a new target (e.g. rust_library, rust_binary).
-func (l *rustLang) inferRuleKind(filename string, dirname *string,
+func (l *rustLang) inferRuleKind(c *config.Config, filename string, dirname *string,
 	response *pb.RustImportsResponse) string {
 
-	if response.Hints.HasProcMacro {
+	hints := response.Hints
+
+	// an explicit crate type takes precedence
+	// NOTE: A crate with several crate types would need a separate target for each, so we just
+	// go with the first one that we recognize.
+	for _, crateType := range hints.CrateTypes {
+		if kind, ok := crateTypeKinds[crateType]; ok {
+			return kind
+		}
+	}
+
+	if hints.HasProcMacro || hints.HasProcMacroDerive {
 		// only proc-macro crates are allowed to have #[proc_macro] functions
 		return "rust_proc_macro"
-	} else if response.Hints.HasMain {
+	} else if hints.PythonModule != "" {
+		// Python extension modules are loaded as C libraries, but may be built with a dedicated
+		// rule
+		return l.GetConfig(c).PythonExtensionKind
+	} else if hints.HasWasmBindgenExports {
+		// exports to JavaScript are only usable from a cdylib
+		return "rust_shared_library"
+	} else if hints.HasMain || hints.NoMain {
 		// while not necessarily true, having a top-level main function is a strong
-		// indicator that this is a binary
+		// indicator that this is a binary; #![no_main] is only meaningful for binaries
 		return "rust_binary"
+	} else if hints.HasCExports && hints.NoStd {
+		// no_std crates exporting C functions are typically linked into C programs for embedded
+		// targets
+		return "rust_static_library"
+	} else if hints.HasCExports {
+		// exporting C functions is a strong indicator that this is meant to be loaded as a C
+		// library
+		return "rust_shared_library"
 	} else if filename == "main.rs" {
 		return "rust_binary"
 	} else if filename == "lib.rs" {
@@ -107,6 +154,9 @@ func (l *rustLang) inferRuleKind(filename string, dirname *string,
 		// assume that sources with tests in a test/tests directory are integration tests
 		// assume that sources with tests with test-like names are integration tests
 		return "rust_test"
+	} else if hints.HasBench && (l.isBenchDir(dirname) || l.isBenchFilename(filename)) {
+		// #[bench] functions without a harness providing main are run by the libtest harness
+		return "rust_test"
 	} else {
 		return "rust_library"
 	}
@@ -124,6 +174,8 @@ type RuleData struct {
 	// dependency aliases: maps package_name -> local_name for renamed dependencies
 	// e.g., { "integrations_http_signatures": "signatures" }
 	aliases map[string]string
+	// the cxx bridges of this crate's sources, if any
+	cxxBridges []label.Label
 }
 
 func getTestCrate(rule *rule.Rule, repo string, pkg string) string {
@@ -177,15 +229,18 @@ func CloneRule(oldRule *rule.Rule) *rule.Rule {
 
 func (l *rustLang) GenerateRules(args language.GenerateArgs) language.GenerateResult {
 	cfg := l.GetConfig(args.Config)
+	var result language.GenerateResult
 	switch cfg.Mode {
 	case modePureBazel:
-		return l.generateRulesPureBazel(args)
+		result = l.generateRulesPureBazel(args)
 	case modeGenerateFromCargo:
-		return l.generateRulesFromCargo(args)
+		result = l.generateRulesFromCargo(args)
 	default:
 		log.Panicf("unrecognized mode")
 		return language.GenerateResult{}
 	}
+	l.generateProtoRules(args, &result)
+	return result
 }
 
 func (l *rustLang) generateRulesPureBazel(args language.GenerateArgs) language.GenerateResult {
@@ -206,17 +261,51 @@ func (l *rustLang) generateRulesPureBazel(args language.GenerateArgs) language.G
 	nonTestRules := []RuleData{}
 	// map of crate test rules; key is the non-rust_test rule name that each one refers to
 	testRules := make(map[string]*rule.Rule)
+	// map of doc test rules; key is the library rule name that each one refers to
+	docTestRules := make(map[string]*rule.Rule)
+
+	// existing cxx bridge rules; key is the bridge source
+	existingCxxBridges := make(map[string]*rule.Rule)
+	// cxx bridge rules, which are added after the crates that use them
+	cxxBridgeRules := []RuleData{}
+
+	// generate or update the rule generating the C++ bindings of a cxx bridge source
+	addCxxBridge := func(file string, response *pb.RustImportsResponse) *label.Label {
+		var ruleName *string
+		if existingRule, ok := existingCxxBridges[file]; ok {
+			name := existingRule.Name()
+			ruleName = &name
+		} else {
+			ruleName = freshRuleName(strings.TrimSuffix(path.Base(file), ".rs")+"_bridge", existingRuleNames)
+			if ruleName == nil {
+				l.Log(args.Config, logWarn, args.File, "could not find a suitable cxx bridge rule name, all candidates already taken")
+				return nil
+			}
+			existingRuleNames[*ruleName] = true
+		}
+
+		bridgeRule := rule.NewRule("rust_cxx_bridge", *ruleName)
+		bridgeRule.SetAttr("src", file)
+		cxxBridgeRules = append(cxxBridgeRules, RuleData{
+			rule:      bridgeRule,
+			responses: []*pb.RustImportsResponse{response},
+		})
+		return &label.Label{Name: *ruleName, Relative: true}
+	}
 
-	addRule := func(rule *rule.Rule, responses []*pb.RustImportsResponse) {
+	addRule := func(rule *rule.Rule, responses []*pb.RustImportsResponse, cxxBridges []label.Label) {
 		ruleData := RuleData{
 			rule:        rule,
 			responses:   responses,
 			testedCrate: nil,
+			cxxBridges:  cxxBridges,
 		}
 
 		result.Gen = append(result.Gen, rule)
 		result.Imports = append(result.Imports, ruleData)
 
+		l.addData(&args, rule, responses)
+
 		if rule.Kind() == "rust_test" {
 			if crateName := getTestCrate(rule, args.Config.RepoName, args.Rel); crateName != "" {
 				if _, ok := testRules[crateName]; ok {
@@ -232,7 +321,12 @@ func (l *rustLang) generateRulesPureBazel(args language.GenerateArgs) language.G
 	if args.File != nil {
 		for _, existingRule := range args.File.Rules {
 			existingRuleNames[existingRule.Name()] = true
+			if l.GetMappedKindInverse(args.Config, existingRule.Kind()) == "rust_cxx_bridge" {
+				existingCxxBridges[existingRule.AttrString("src")] = existingRule
+			}
+		}
 
+		for _, existingRule := range args.File.Rules {
 			unmappedKind := l.GetMappedKindInverse(args.Config, existingRule.Kind())
 
 			if SliceContains(commonDefs, unmappedKind) {
@@ -244,36 +338,79 @@ func (l *rustLang) generateRulesPureBazel(args language.GenerateArgs) language.G
 				rule.SetKind(unmappedKind)
 
 				responses := []*pb.RustImportsResponse{}
+				cxxBridges := []label.Label{}
 
 				enabled_features := []string{}
 				for _, feature := range rule.AttrStrings("crate_features") {
 					enabled_features = append(enabled_features, feature)
 				}
+				edition := l.crateEdition(args.Config, rule.AttrString("edition"))
 
 				for _, file := range rule.AttrStrings("srcs") {
 					filesInExistingRules[file] = true
 
 					if strings.HasSuffix(file, ".rs") {
-						response := l.parseFile(args.Config, file, enabled_features, &args)
+						response := l.parseFile(args.Config, file, enabled_features, []string{}, edition, false, &args)
 						if response != nil {
 							responses = append(responses, response)
+							if response.Hints.HasCxxBridge {
+								if bridge := addCxxBridge(file, response); bridge != nil {
+									cxxBridges = append(cxxBridges, *bridge)
+								}
+							}
 						}
 					}
 				}
 
-				addRule(rule, responses)
+				for _, file := range addIncludedSrcs(rule, responses) {
+					filesInExistingRules[file] = true
+				}
+
+				addRule(rule, responses, cxxBridges)
+			} else if SliceContains(docTestDefs, unmappedKind) {
+				if crateName := getTestCrate(existingRule, args.Config.RepoName, args.Rel); crateName != "" {
+					docTestRules[crateName] = existingRule
+				}
+			}
+		}
+	}
+
+	// trybuild UI tests aren't compiled on their own
+	isTrybuildTest := func(file string) bool {
+		for _, pattern := range l.GetConfig(args.Config).TrybuildPatterns {
+			if matchGlob(pattern, path.Join(args.Rel, file)) {
+				return true
 			}
 		}
+		return false
 	}
 
+	// parse all of the new sources first, since sources included with include! by other sources
+	// don't get their own targets
+	newFiles := []string{}
+	newResponses := map[string]*pb.RustImportsResponse{}
 	for _, file := range args.RegularFiles {
-		if !filesInExistingRules[file] && strings.HasSuffix(file, ".rs") {
-			response := l.parseFile(args.Config, file, []string{}, &args)
+		if !filesInExistingRules[file] && strings.HasSuffix(file, ".rs") && !isTrybuildTest(file) {
+			newFiles = append(newFiles, file)
+			newResponses[file] = l.requestParse(args.Config, file, []string{}, []string{}, l.crateEdition(args.Config, ""), false, &args)
+		}
+	}
+	for _, response := range newResponses {
+		if response.Success {
+			for _, file := range response.IncludedSrcs {
+				filesInExistingRules[file] = true
+			}
+		}
+	}
+
+	for _, file := range newFiles {
+		if !filesInExistingRules[file] {
+			response := l.checkParseResponse(args.Config, file, newResponses[file])
 			if response == nil {
 				continue
 			}
 
-			inferredKind := l.inferRuleKind(file, dirname, response)
+			inferredKind := l.inferRuleKind(args.Config, file, dirname, response)
 
 			ruleName := freshRuleName(strings.TrimSuffix(file, ".rs"), existingRuleNames)
 			if ruleName == nil {
@@ -283,10 +420,31 @@ func (l *rustLang) generateRulesPureBazel(args language.GenerateArgs) language.G
 
 			rule := rule.NewRule(inferredKind, *ruleName)
 			rule.SetAttr("srcs", []string{file})
+			crateName := response.Hints.CrateName
+			if crateName == "" {
+				// Python can only import an extension module under the name of its #[pymodule]
+				crateName = response.Hints.PythonModule
+			}
+			if crateName != "" && crateName != *ruleName {
+				rule.SetAttr("crate_name", crateName)
+			}
+			if response.Hints.HasBench && (inferredKind == "rust_binary" || inferredKind == "rust_test") {
+				// match the targets generated for [[bench]] entries in cargo mode
+				rule.SetAttr("tags", []string{"bench"})
+			}
+			l.addNightlyAttrs(args.Config, rule, []*pb.RustImportsResponse{response})
+
+			cxxBridges := []label.Label{}
+			if response.Hints.HasCxxBridge {
+				if bridge := addCxxBridge(file, response); bridge != nil {
+					cxxBridges = append(cxxBridges, *bridge)
+				}
+			}
 
 			responses := []*pb.RustImportsResponse{response}
+			addIncludedSrcs(rule, responses)
 
-			addRule(rule, responses)
+			addRule(rule, responses, cxxBridges)
 		}
 	}
 
@@ -312,9 +470,11 @@ func (l *rustLang) generateRulesPureBazel(args language.GenerateArgs) language.G
 
 				testRule = rule.NewRule("rust_test", *testRuleName)
 				testRule.SetAttr("crate", ":"+ruleData.rule.Name())
+				l.addNightlyAttrs(args.Config, testRule, ruleData.responses)
 			} else {
 				testRule = CloneRule(existingTestRule)
 			}
+			l.addData(&args, testRule, ruleData.responses)
 
 			result.Gen = append(result.Gen, testRule)
 			result.Imports = append(result.Imports, RuleData{
@@ -325,22 +485,352 @@ func (l *rustLang) generateRulesPureBazel(args language.GenerateArgs) language.G
 		} else {
 			// TODO: remove test target if we no longer have any tests
 		}
+
+		if ruleData.rule.Kind() != "rust_library" {
+			// rustdoc only runs doctests for libraries
+			continue
+		}
+
+		hasDocTest := false
+		for _, response := range ruleData.responses {
+			if response.Hints.HasDocTest {
+				hasDocTest = true
+			}
+		}
+
+		existingDocTestRule := docTestRules[ruleData.rule.Name()]
+
+		if hasDocTest || existingDocTestRule != nil {
+			// create or update the corresponding doc test target
+			var docTestRule *rule.Rule
+			if existingDocTestRule == nil {
+				docTestRuleName := freshRuleName(ruleData.rule.Name()+"_doc_test", existingRuleNames)
+				if docTestRuleName == nil {
+					l.Log(args.Config, logWarn, args.File, "could not find a suitable doc test rule name, all candidates already taken")
+					continue
+				}
+
+				docTestRule = rule.NewRule("rust_doc_test", *docTestRuleName)
+				docTestRule.SetAttr("crate", ":"+ruleData.rule.Name())
+				l.addNightlyAttrs(args.Config, docTestRule, ruleData.responses)
+			} else {
+				docTestRule = CloneRule(existingDocTestRule)
+				docTestRule.SetKind("rust_doc_test")
+			}
+
+			result.Gen = append(result.Gen, docTestRule)
+			result.Imports = append(result.Imports, RuleData{
+				rule:        docTestRule,
+				responses:   ruleData.responses,
+				testedCrate: ruleData.rule,
+			})
+		}
+	}
+
+	for _, ruleData := range nonTestRules {
+		l.generateWasmBindgen(args.Config, &args, ruleData.rule, ruleData.responses, &result)
+	}
+
+	for _, ruleData := range cxxBridgeRules {
+		result.Gen = append(result.Gen, ruleData.rule)
+		result.Imports = append(result.Imports, ruleData)
 	}
 
 	return result
 }
 
-func (l *rustLang) parseFile(c *config.Config, file string, enabledFeatures []string, args *language.GenerateArgs) *pb.RustImportsResponse {
+// addIncludedSrcs adds the sources included with include! by the sources of a rule to its srcs,
+// since rules_rust needs all of the sources of a crate. Returns the included sources.
+func addIncludedSrcs(r *rule.Rule, responses []*pb.RustImportsResponse) []string {
+	srcs := r.AttrStrings("srcs")
+	if srcs == nil {
+		// e.g. a glob, which we leave alone
+		return nil
+	}
+
+	included := []string{}
+	for _, response := range responses {
+		for _, file := range response.IncludedSrcs {
+			included = append(included, file)
+			if !SliceContains(srcs, file) {
+				srcs = append(srcs, file)
+			}
+		}
+	}
+	r.SetAttr("srcs", srcs)
+
+	return included
+}
+
+// addData adds the files that the sources of a rule read at runtime to its data: runfiles that are
+// looked up with rlocation, and for tests, files read through paths relative to
+// CARGO_MANIFEST_DIR, insta snapshots, and trybuild UI tests. An existing data attribute is left
+// alone.
+func (l *rustLang) addData(args *language.GenerateArgs, r *rule.Rule, responses []*pb.RustImportsResponse) {
+	isTest := r.Kind() == "rust_test"
+
+	hasSnapshots := false
+	if isTest {
+		for _, response := range responses {
+			hasSnapshots = hasSnapshots || response.Hints.HasFileSnapshot
+		}
+	}
+	if hasSnapshots && r.Attr("env") == nil {
+		// insta finds snapshots relative to the workspace root, which is the runfiles directory
+		r.SetAttr("env", map[string]string{"INSTA_WORKSPACE_ROOT": "."})
+	}
+
+	if r.Attr("data") != nil {
+		return
+	}
+
+	data := map[string]bool{}
+	for _, response := range responses {
+		if isTest {
+			for _, f := range response.Data {
+				data[f] = true
+			}
+			// snapshots that haven't been accepted yet don't exist, and an empty glob is an error
+			for _, dir := range response.Hints.SnapshotDirs {
+				if info, err := os.Stat(filepath.Join(args.Dir, dir)); err == nil && info.IsDir() {
+					data[dir+"/**"] = true
+				}
+			}
+			for _, pattern := range response.TrybuildPatterns {
+				for _, file := range globFiles(args.Dir, pattern) {
+					uiTestFiles := []string{file}
+					if stderr := strings.TrimSuffix(file, ".rs") + ".stderr"; fileExists(stderr, args) {
+						uiTestFiles = append(uiTestFiles, stderr)
+					}
+					for _, f := range uiTestFiles {
+						if dataLabel, ok := fileLabel(args.Config, args.Rel, path.Join(args.Rel, f)); ok {
+							data[dataLabel] = true
+						}
+					}
+				}
+			}
+		}
+		for _, runfile := range response.Runfiles {
+			if dataLabel, ok := runfileLabel(args.Config, args.Rel, runfile); ok {
+				data[dataLabel] = true
+			} else {
+				l.Log(args.Config, logWarn, label.New("", args.Rel, r.Name()),
+					"could not resolve runfile %s, add it to data manually", runfile)
+			}
+		}
+	}
+	if len(data) > 0 {
+		r.SetAttr("data", fileListValue(data))
+	}
+}
+
+// addNightlyAttrs adds the configured tags and target_compatible_with constraints to a new rule
+// whose sources enable nightly features with #![feature(...)].
+func (l *rustLang) addNightlyAttrs(c *config.Config, r *rule.Rule, responses []*pb.RustImportsResponse) {
+	hasNightlyFeatures := false
+	for _, response := range responses {
+		hasNightlyFeatures = hasNightlyFeatures || len(response.Hints.NightlyFeatures) > 0
+	}
+	if !hasNightlyFeatures {
+		return
+	}
+
+	cfg := l.GetConfig(c)
+	if len(cfg.NightlyTags) > 0 {
+		tags := r.AttrStrings("tags")
+		for _, tag := range cfg.NightlyTags {
+			if !SliceContains(tags, tag) {
+				tags = append(tags, tag)
+			}
+		}
+		r.SetAttr("tags", tags)
+	}
+	if len(cfg.NightlyTargetCompatibleWith) > 0 {
+		r.SetAttr("target_compatible_with", cfg.NightlyTargetCompatibleWith)
+	}
+}
+
+// generateWasmBindgen generates a rust_wasm_bindgen target for a shared library with #[wasm_bindgen]
+// exports, unless it already has one.
+func (l *rustLang) generateWasmBindgen(c *config.Config, args *language.GenerateArgs,
+	crateRule *rule.Rule, responses []*pb.RustImportsResponse, result *language.GenerateResult) {
+	if crateRule.Kind() != "rust_shared_library" {
+		return
+	}
+	hasExports := false
+	for _, response := range responses {
+		hasExports = hasExports || response.Hints.HasWasmBindgenExports
+	}
+	if !hasExports {
+		return
+	}
+
+	existingRuleNames := map[string]bool{}
+	if args.File != nil {
+		for _, existingRule := range args.File.Rules {
+			existingRuleNames[existingRule.Name()] = true
+			if l.GetMappedKindInverse(c, existingRule.Kind()) == "rust_wasm_bindgen" &&
+				existingRule.AttrString("wasm_file") == ":"+crateRule.Name() {
+				return
+			}
+		}
+	}
+	for _, r := range result.Gen {
+		existingRuleNames[r.Name()] = true
+	}
+
+	ruleName := freshRuleName(crateRule.Name()+"_wasm", existingRuleNames)
+	if ruleName == nil {
+		l.Log(c, logWarn, args.File, "could not find a suitable wasm_bindgen rule name, all candidates already taken")
+		return
+	}
+
+	wasmRule := rule.NewRule("rust_wasm_bindgen", *ruleName)
+	wasmRule.SetAttr("target", l.GetConfig(c).WasmBindgenTarget)
+	wasmRule.SetAttr("wasm_file", ":"+crateRule.Name())
+
+	result.Gen = append(result.Gen, wasmRule)
+	result.Imports = append(result.Imports, RuleData{rule: wasmRule})
+}
+
+// runfileLabel converts the rlocation path of a runfile, e.g. _main/pkg/data.txt, to a data label
+// for a rule in the package rel, see fileLabel. Only files in the main repo can be resolved.
+func runfileLabel(c *config.Config, rel string, runfile string) (string, bool) {
+	repo, file, ok := strings.Cut(runfile, "/")
+	if !ok || (repo != "_main" && repo != c.RepoName) {
+		return "", false
+	}
+	return fileLabel(c, rel, file)
+}
+
+// fileLabel converts the path of a file relative to the repo root to a data label for a rule in the
+// package rel: a plain path for files in the same package, and //pkg:data.txt otherwise.
+// Directories can't be resolved.
+func fileLabel(c *config.Config, rel string, file string) (string, bool) {
+	file = path.Clean(file)
+	if file == "." || file == ".." || strings.HasPrefix(file, "../") {
+		return "", false
+	}
+	if info, err := os.Stat(filepath.Join(c.RepoRoot, file)); err == nil && info.IsDir() {
+		return "", false
+	}
+
+	// find the closest package containing the file
+	pkg := path.Dir(file)
+	for pkg != "." && pkg != rel && !isPackageDir(c, pkg) {
+		pkg = path.Dir(pkg)
+	}
+	if pkg == "." {
+		pkg = ""
+	}
+	name := strings.TrimPrefix(file, pkg+"/")
+
+	if pkg == rel {
+		return name, true
+	}
+	return label.New("", pkg, name).String(), true
+}
+
+// findTrybuildPatterns returns the patterns of the trybuild UI tests run by the sources in the
+// package rel, relative to the repo root. Gazelle generates subdirectories before their parents, so
+// this is done while configuring the package, to skip UI tests in subdirectories.
+func (l *rustLang) findTrybuildPatterns(c *config.Config, rel string) []string {
+	dir := filepath.Join(c.RepoRoot, rel)
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		return nil
+	}
+
+	patterns := []string{}
+	for _, entry := range entries {
+		if entry.IsDir() || !strings.HasSuffix(entry.Name(), ".rs") {
+			continue
+		}
+		// avoid parsing every file twice
+		contents, err := os.ReadFile(filepath.Join(dir, entry.Name()))
+		if err != nil || !strings.Contains(string(contents), "trybuild") {
+			continue
+		}
+
+		response, err := l.Parser.Parse(&pb.RustImportsRequest{
+			AbsolutePath: filepath.Join(dir, entry.Name()),
+			RelativePath: entry.Name(),
+			Edition:      l.crateEdition(c, ""),
+		})
+		if err != nil || !response.Success {
+			continue
+		}
+		for _, pattern := range response.TrybuildPatterns {
+			patterns = append(patterns, path.Join(rel, pattern))
+		}
+	}
+	return patterns
+}
+
+// globFiles returns the files under dir that match a glob pattern, relative to dir.
+func globFiles(dir string, pattern string) []string {
+	// only walk the part of the tree that can match
+	base := ""
+	segments := strings.Split(path.Clean(pattern), "/")
+	for len(segments) > 1 && !strings.ContainsAny(segments[0], "*?[") {
+		base = path.Join(base, segments[0])
+		segments = segments[1:]
+	}
+
+	files := []string{}
+	filepath.WalkDir(filepath.Join(dir, base), func(p string, d fs.DirEntry, err error) error {
+		if err != nil || d.IsDir() {
+			return nil
+		}
+		if rel, err := filepath.Rel(dir, p); err == nil && matchGlob(pattern, filepath.ToSlash(rel)) {
+			files = append(files, filepath.ToSlash(rel))
+		}
+		return nil
+	})
+	return files
+}
+
+func isPackageDir(c *config.Config, dir string) bool {
+	for _, buildFileName := range c.ValidBuildFileNames {
+		if info, err := os.Stat(filepath.Join(c.RepoRoot, dir, buildFileName)); err == nil && !info.IsDir() {
+			return true
+		}
+	}
+	return false
+}
+
+func (l *rustLang) parseFile(c *config.Config, file string, enabledFeatures []string,
+	enabledCfgs []string, edition string, buildScript bool, args *language.GenerateArgs) *pb.RustImportsResponse {
+	response := l.requestParse(c, file, enabledFeatures, enabledCfgs, edition, buildScript, args)
+	return l.checkParseResponse(c, file, response)
+}
+
+// requestParse parses a file, even if it turns out to contain errors. buildScript should be set for
+// the files of a build script, so that the files that it reads and the cfgs that it enables are
+// reported.
+func (l *rustLang) requestParse(c *config.Config, file string, enabledFeatures []string,
+	enabledCfgs []string, edition string, buildScript bool, args *language.GenerateArgs) *pb.RustImportsResponse {
 	request := &pb.RustImportsRequest{
-		AbsolutePath:    path.Join(args.Dir, file),
-		RelativePath:    file,
-		EnabledFeatures: enabledFeatures,
+		AbsolutePath:     path.Join(args.Dir, file),
+		RelativePath:     file,
+		EnabledFeatures:  enabledFeatures,
+		Edition:          edition,
+		PathAttributes:   l.GetConfig(c).PathAttributes,
+		CompileDataRules: l.GetConfig(c).CompileDataRules,
+		EnabledCfgs:      enabledCfgs,
+		BuildScript:      buildScript,
 	}
 
 	response, err := l.Parser.Parse(request)
 	if err != nil {
 		l.Log(c, logFatal, file, "failed to parse %s: %v", file, err)
 	}
+	return response
+}
+
+// checkParseResponse reports a parse error in a file, returning nil in that case.
+func (l *rustLang) checkParseResponse(c *config.Config, file string,
+	response *pb.RustImportsResponse) *pb.RustImportsResponse {
 	if !response.Success {
 		// TODO: It's debatable whether this should be a warning or a fatal error. Having a warning
 		// is probably the least surprising, although it could be frustrating to have a bunch of new
@@ -351,6 +841,16 @@ func (l *rustLang) parseFile(c *config.Config, file string, enabledFeatures []st
 	return response
 }
 
+// crateEdition returns the edition used to parse the sources of a crate, given the edition that the
+// crate specifies (if any). Falls back to the default edition; an empty string means the parser's
+// default.
+func (l *rustLang) crateEdition(c *config.Config, edition string) string {
+	if edition != "" {
+		return edition
+	}
+	return l.GetConfig(c).DefaultEdition
+}
+
 func (l *rustLang) generateRulesFromCargo(args language.GenerateArgs) language.GenerateResult {
 	result := language.GenerateResult{}
 	cfg := l.GetConfig(args.Config)
@@ -359,6 +859,9 @@ func (l *rustLang) generateRulesFromCargo(args language.GenerateArgs) language.G
 	parentCrateName := ""
 	parentCrateEdition := ""
 	var enabledFeatures []string = []string{}
+	var enabledCfgs []string = []string{}
+	// the build script is parsed before the other targets, since it may enable cfgs for them
+	var buildScriptResponses map[string]*pb.RustImportsResponse
 	for _, src := range args.RegularFiles {
 		if src == "build.rs" {
 			hasBuildScript = true
@@ -393,6 +896,11 @@ func (l *rustLang) generateRulesFromCargo(args language.GenerateArgs) language.G
 				}
 				sort.Strings(enabledFeatures)
 
+				if hasBuildScript {
+					buildScriptResponses = l.parseBuildScript(args.Config, &args, parentCrateEdition, enabledFeatures)
+					enabledCfgs = buildScriptCfgs(buildScriptResponses)
+				}
+
 				// Extract dependency aliases from Cargo.toml
 				for _, alias := range response.DependencyAliases {
 					dependencyAliases[alias.PackageName] = alias.LocalName
@@ -441,26 +949,29 @@ func (l *rustLang) generateRulesFromCargo(args language.GenerateArgs) language.G
 						kind = "rust_proc_macro"
 					}
 
-					l.generateCargoRule(args.Config, &args, response.Library, kind, suffix, []string{}, hasBuildScript, hasMainRs, parentCrateName, parentCrateEdition, enabledFeatures, dependencyAliases, &result)
+					l.generateCargoRule(args.Config, &args, response.Library, kind, suffix, []string{}, hasBuildScript, hasMainRs, parentCrateName, parentCrateEdition, enabledFeatures, enabledCfgs, dependencyAliases, &result)
 				}
 				for _, binary := range response.Binaries {
-					l.generateCargoRule(args.Config, &args, binary, "rust_binary", "", []string{}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, dependencyAliases, &result)
+					l.generateCargoRule(args.Config, &args, binary, "rust_binary", "", []string{}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, enabledCfgs, dependencyAliases, &result)
 				}
 				for _, test := range response.Tests {
-					l.generateCargoRule(args.Config, &args, test, "rust_test", "", []string{}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, dependencyAliases, &result)
+					l.generateCargoRule(args.Config, &args, test, "rust_test", "", []string{}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, enabledCfgs, dependencyAliases, &result)
 				}
 				for _, bench := range response.Benches {
-					l.generateCargoRule(args.Config, &args, bench, "rust_binary", "", []string{"bench"}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, dependencyAliases, &result)
+					l.generateCargoRule(args.Config, &args, bench, "rust_binary", "", []string{"bench"}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, enabledCfgs, dependencyAliases, &result)
 				}
 				for _, example := range response.Examples {
-					l.generateCargoRule(args.Config, &args, example, "rust_binary", "", []string{"example"}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, dependencyAliases, &result)
+					l.generateCargoRule(args.Config, &args, example, "rust_binary", "", []string{"example"}, hasBuildScript, false, parentCrateName, parentCrateEdition, enabledFeatures, enabledCfgs, dependencyAliases, &result)
 				}
 			}
 		}
 	}
 
 	if hasBuildScript {
-		l.generateBuildScript(args.Config, &args, parentCrateName, parentCrateEdition, enabledFeatures, dependencyAliases, &result)
+		if buildScriptResponses == nil {
+			buildScriptResponses = l.parseBuildScript(args.Config, &args, parentCrateEdition, enabledFeatures)
+		}
+		l.generateBuildScript(args.Config, &args, buildScriptResponses, parentCrateName, parentCrateEdition, enabledFeatures, dependencyAliases, &result)
 	}
 
 	existingRuleNames := make(map[string]bool)
@@ -499,6 +1010,8 @@ func (l *rustLang) generateRulesFromCargo(args language.GenerateArgs) language.G
 				if cfg.ExtractCargoLints {
 					testRule.SetAttr("lint_config", ":workspace_lints")
 				}
+				l.addNightlyAttrs(args.Config, testRule, ruleData.responses)
+				l.addData(&args, testRule, ruleData.responses)
 
 				result.Gen = append(result.Gen, testRule)
 				result.Imports = append(result.Imports, RuleData{
@@ -510,6 +1023,37 @@ func (l *rustLang) generateRulesFromCargo(args language.GenerateArgs) language.G
 				})
 			}
 		}
+
+		if ruleData.rule.Kind() == "rust_library" {
+			// rustdoc only runs doctests for libraries
+			hasDocTest := false
+			for _, response := range ruleData.responses {
+				if response.Hints.HasDocTest {
+					hasDocTest = true
+				}
+			}
+
+			if hasDocTest {
+				docTestRuleName := freshRuleName(ruleData.rule.Name()+"_doc_test", existingRuleNames)
+				if docTestRuleName == nil {
+					l.Log(args.Config, logWarn, args.File, "could not find a suitable doc test rule name, all candidates already taken")
+					continue
+				}
+
+				docTestRule := rule.NewRule("rust_doc_test", *docTestRuleName)
+				docTestRule.SetAttr("crate", ":"+ruleData.rule.Name())
+				l.addNightlyAttrs(args.Config, docTestRule, ruleData.responses)
+
+				result.Gen = append(result.Gen, docTestRule)
+				result.Imports = append(result.Imports, RuleData{
+					rule:            docTestRule,
+					responses:       ruleData.responses,
+					testedCrate:     ruleData.rule,
+					parentCrateName: parentCrateName,
+					aliases:         dependencyAliases,
+				})
+			}
+		}
 	}
 
 	// If srcs_glob is enabled, we need to clear the srcs attribute from existing rules
@@ -534,7 +1078,8 @@ func (l *rustLang) generateRulesFromCargo(args language.GenerateArgs) language.G
 func (l *rustLang) generateCargoRule(c *config.Config, args *language.GenerateArgs,
 	crateInfo *pb.CargoCrateInfo, kind string, suffix string, tags []string,
 	hasBuildScript bool, hasMainRs bool, parentCrateName string, parentCrateEdition string,
-	enabledFeatures []string, dependencyAliases map[string]string, result *language.GenerateResult) {
+	enabledFeatures []string, enabledCfgs []string, dependencyAliases map[string]string,
+	result *language.GenerateResult) {
 
 	targetName := crateInfo.Name + suffix
 	crateName := crateInfo.Name
@@ -552,11 +1097,12 @@ func (l *rustLang) generateCargoRule(c *config.Config, args *language.GenerateAr
 
 	// traverse all files we know about to determine the full module structure
 	importsResponses := map[string]*pb.RustImportsResponse{}
+	edition := l.crateEdition(c, parentCrateEdition)
 	for _, src := range crateInfo.Srcs {
 		// It is possible for declared files to be absent if they are
 		// supposed to be produced by the build script of the crate.
 		if fileExists(src, args) {
-			l.discoverModule(c, src, enabledFeatures, args, &importsResponses, true)
+			l.discoverModule(c, src, enabledFeatures, enabledCfgs, edition, false, args, &importsResponses, true)
 		}
 	}
 
@@ -573,6 +1119,13 @@ func (l *rustLang) generateCargoRule(c *config.Config, args *language.GenerateAr
 			responses = append(responses, response)
 		}
 	}
+	for _, response := range responses {
+		for _, f := range response.IncludedSrcs {
+			if _, ok := importsResponses[f]; !ok && !SliceContains(srcs, f) {
+				srcs = append(srcs, f)
+			}
+		}
+	}
 
 	newRule := rule.NewRule(kind, targetName)
 
@@ -587,7 +1140,7 @@ func (l *rustLang) generateCargoRule(c *config.Config, args *language.GenerateAr
 		}
 	}
 	newRule.SetAttr("visibility", []string{"//visibility:public"})
-	newRule.SetAttr("compile_data", setToSortedVector(compile_data))
+	newRule.SetAttr("compile_data", fileListValue(compile_data))
 
 	if targetName != crateName {
 		newRule.SetAttr("crate_name", crateName)
@@ -613,6 +1166,9 @@ func (l *rustLang) generateCargoRule(c *config.Config, args *language.GenerateAr
 		newRule.SetAttr("lint_config", ":workspace_lints")
 	}
 
+	l.addNightlyAttrs(c, newRule, responses)
+	l.addData(args, newRule, responses)
+
 	var buildScript *label.Label = nil
 	if hasBuildScript && (kind == "rust_library" || kind == "rust_binary") {
 		build_script_label, err := label.Parse(":build_script")
@@ -633,31 +1189,64 @@ func (l *rustLang) generateCargoRule(c *config.Config, args *language.GenerateAr
 	})
 }
 
-func (l *rustLang) generateBuildScript(c *config.Config, args *language.GenerateArgs,
-	parentCrateName string, parentCrateEdition string, enabledFeatures []string,
-	dependencyAliases map[string]string, result *language.GenerateResult) {
+// parseBuildScript parses build.rs and the modules that it declares.
+func (l *rustLang) parseBuildScript(c *config.Config, args *language.GenerateArgs,
+	parentCrateEdition string, enabledFeatures []string) map[string]*pb.RustImportsResponse {
 	importsResponses := map[string]*pb.RustImportsResponse{}
-	l.discoverModule(c, "build.rs", enabledFeatures, args, &importsResponses, true)
+	edition := l.crateEdition(c, parentCrateEdition)
+	l.discoverModule(c, "build.rs", enabledFeatures, []string{}, edition, true, args, &importsResponses, true)
+	return importsResponses
+}
 
+// buildScriptCfgs returns the cfgs that a build script enables for the rest of the crate with
+// cargo:rustc-cfg.
+func buildScriptCfgs(importsResponses map[string]*pb.RustImportsResponse) []string {
+	cfgs := map[string]bool{}
+	for _, response := range importsResponses {
+		if response == nil {
+			continue
+		}
+		for _, cfg := range response.BuildScriptCfgs {
+			cfgs[cfg] = true
+		}
+	}
+	return setToSortedVector(cfgs)
+}
+
+func (l *rustLang) generateBuildScript(c *config.Config, args *language.GenerateArgs,
+	importsResponses map[string]*pb.RustImportsResponse, parentCrateName string,
+	parentCrateEdition string, enabledFeatures []string, dependencyAliases map[string]string,
+	result *language.GenerateResult) {
 	srcs := []string{}
 	compile_data := map[string]bool{"Cargo.toml": true}
+	data := map[string]bool{}
 	responses := []*pb.RustImportsResponse{}
 
 	for src, response := range importsResponses {
 		srcs = append(srcs, src)
-		for _, f := range response.CompileData {
-			compile_data[f] = true
-		}
 		if response != nil {
+			for _, f := range response.CompileData {
+				compile_data[f] = true
+			}
+			for _, f := range response.BuildScriptData {
+				data[f] = true
+			}
 			responses = append(responses, response)
 		}
 	}
+	// the build script's own sources (e.g. rerun-if-changed=build.rs) are already in srcs
+	for _, src := range srcs {
+		delete(data, src)
+	}
 
 	newRule := rule.NewRule("cargo_build_script", "build_script")
 	newRule.SetAttr("srcs", srcs)
 	newRule.SetAttr("visibility", []string{"//visibility:public"})
-	newRule.SetAttr("compile_data", setToSortedVector(compile_data))
+	newRule.SetAttr("compile_data", fileListValue(compile_data))
 	newRule.SetAttr("crate_root", "build.rs")
+	if len(data) > 0 {
+		newRule.SetAttr("data", fileListValue(data))
+	}
 
 	cfg := l.GetConfig(args.Config)
 	if parentCrateEdition != "" && parentCrateEdition != cfg.DefaultEdition {
@@ -678,14 +1267,15 @@ func (l *rustLang) generateBuildScript(c *config.Config, args *language.Generate
 	})
 }
 
-func (l *rustLang) discoverModule(c *config.Config, file string, enabledFeatures []string, args *language.GenerateArgs,
+func (l *rustLang) discoverModule(c *config.Config, file string, enabledFeatures []string,
+	enabledCfgs []string, edition string, buildScript bool, args *language.GenerateArgs,
 	importsResponses *map[string]*pb.RustImportsResponse, isModRoot bool) {
 
 	if _, ok := (*importsResponses)[file]; ok {
 		return
 	}
 
-	response := l.parseFile(c, file, enabledFeatures, args)
+	response := l.parseFile(c, file, enabledFeatures, enabledCfgs, edition, buildScript, args)
 	(*importsResponses)[file] = response
 
 	if response != nil {
@@ -717,7 +1307,7 @@ func (l *rustLang) discoverModule(c *config.Config, file string, enabledFeatures
 				continue
 			}
 
-			l.discoverModule(c, externModPath, enabledFeatures, args, importsResponses, childIsModRoot)
+			l.discoverModule(c, externModPath, enabledFeatures, enabledCfgs, edition, buildScript, args, importsResponses, childIsModRoot)
 		}
 	}
 }
@@ -749,3 +1339,25 @@ func setToSortedVector(src map[string]bool) []string {
 	sort.Strings(result)
 	return result
 }
+
+// fileListValue returns the value of an attribute like compile_data or data. Files are listed
+// explicitly, while patterns (e.g. from include_dir!) are added as a glob.
+func fileListValue(fileSet map[string]bool) interface{} {
+	files := []string{}
+	patterns := []string{}
+	for _, f := range setToSortedVector(fileSet) {
+		if strings.ContainsAny(f, "*?[") {
+			patterns = append(patterns, f)
+		} else {
+			files = append(files, f)
+		}
+	}
+	if len(patterns) == 0 {
+		return files
+	}
+	return &bzl.BinaryExpr{
+		X:  rule.ExprFromValue(files),
+		Op: "+",
+		Y:  rule.ExprFromValue(rule.GlobValue{Patterns: patterns}),
+	}
+}
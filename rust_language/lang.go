@@ -7,6 +7,7 @@ import (
 	"strings"
 
 	"github.com/bazelbuild/bazel-gazelle/config"
+	"github.com/bazelbuild/bazel-gazelle/label"
 	"github.com/bazelbuild/bazel-gazelle/language"
 	"github.com/bazelbuild/bazel-gazelle/rule"
 )
@@ -14,6 +15,16 @@ import (
 var (
 	langName          string = "rust"
 	procMacroLangName string = "rust_proc_macro"
+
+	// Protobuf packages, e.g. my.pkg.v1, which are indexed for the rules that generate their code.
+	protoPackageLangName string = "rust_proto_package"
+	// .proto files, e.g. my/pkg/v1/types.proto, which are indexed for the rules that generate their
+	// code.
+	protoFileLangName string = "rust_proto_file"
+
+	// C++ headers included by cxx bridges, e.g. foo/bar.h, which can only be resolved with
+	// gazelle:resolve.
+	cxxHeaderLangName string = "rust_cxx_header"
 )
 
 // Available directives
@@ -70,8 +81,68 @@ var (
 	// When enabled in generate_from_cargo mode, creates extract_cargo_lints targets
 	// and adds lint_config attributes to all generated targets.
 	extractCargoLintsDirective string = "rust_extract_cargo_lints"
+
+	// How to report heuristic imports that can't be resolved, e.g. paths found inside the input of
+	// an unknown macro or that may have been brought into scope by a glob import.
+	// usage: # gazelle:rust_unresolved_heuristic_imports <error|warn|ignore>
+	unresolvedHeuristicImportsDirective string = "rust_unresolved_heuristic_imports"
+
+	unresolvedError  string = "error"
+	unresolvedWarn   string = "warn"
+	unresolvedIgnore string = "ignore"
+
+	// Add a helper attribute key whose value refers to code, in addition to the built-in ones like
+	// serde.with. String values are parsed as paths for the purpose of finding dependencies.
+	// usage: # gazelle:rust_path_attribute <attribute>.<key>
+	pathAttributeDirective string = "rust_path_attribute"
+
+	// Add a macro or attribute that reads files at compile time, in addition to the built-in ones
+	// like include_dir!. The kind is file, dir, or glob (relative to the package, or to the source
+	// file with a source_ prefix), or else a fixed glob that is added whenever the macro is used.
+	// usage: # gazelle:rust_compile_data <macro>!=<kind>
+	//        # gazelle:rust_compile_data <attribute>=<kind>
+	compileDataDirective string = "rust_compile_data"
+
+	// Add a tag to new targets for crates that enable nightly features with #![feature(...)],
+	// e.g. to exclude them from builds with a stable toolchain.
+	// usage: # gazelle:rust_nightly_tag <tag>
+	nightlyTagDirective string = "rust_nightly_tag"
+
+	// Add a constraint to the target_compatible_with of new targets for crates that enable nightly
+	// features, e.g. a constraint that is only satisfied by a nightly toolchain.
+	// usage: # gazelle:rust_nightly_target_compatible_with <label>
+	nightlyTargetCompatibleWithDirective string = "rust_nightly_target_compatible_with"
+
+	// Map a native library linked with #[link(name = "...")] to the target that provides it, e.g.
+	// a cc_library. The target is added to the deps of crates that link the library.
+	// usage: # gazelle:rust_native_lib <library name> <label>
+	nativeLibDirective string = "rust_native_lib"
+
+	// Generate a Rust proto target for each proto_library that doesn't have one yet:
+	//  - none (default): don't generate Rust proto targets
+	//  - prost: generate rust_prost_library targets from rules_rust_prost
+	//  - rules_rust: generate rust_proto_library targets, or rust_grpc_library targets for protos
+	//    with services, from rules_rust
+	// usage: # gazelle:rust_proto_rules <none|prost|rules_rust>
+	protoRulesDirective string = "rust_proto_rules"
+
+	protoRulesNone      string = "none"
+	protoRulesProst     string = "prost"
+	protoRulesRulesRust string = "rules_rust"
+
+	// The kind of new targets for Python extension modules, i.e. crates with a PyO3 #[pymodule].
+	// Defaults to rust_shared_library; use map_kind to build them with a PyO3-specific rule.
+	// usage: # gazelle:rust_python_extension_kind <kind>
+	pythonExtensionKindDirective string = "rust_python_extension_kind"
+
+	// The target environment of the JavaScript bindings generated for shared libraries with
+	// #[wasm_bindgen] exports, i.e. the target attribute of their rust_wasm_bindgen targets.
+	// usage: # gazelle:rust_wasm_bindgen_target <bundler|web|nodejs|no-modules|deno>
+	wasmBindgenTargetDirective string = "rust_wasm_bindgen_target"
 )
 
+var wasmBindgenTargets = []string{"bundler", "web", "nodejs", "no-modules", "deno"}
+
 type rustConfig struct {
 	Mode               string
 	LockfileCrates     *LockfileCrates
@@ -84,6 +155,26 @@ type rustConfig struct {
 	SrcsGlob           bool
 	IgnoredImports     map[string]bool
 	ExtractCargoLints  bool
+	// How to report unresolved heuristic imports; one of error, warn, or ignore.
+	UnresolvedHeuristicImports string
+	// Additional helper attribute keys whose values refer to code, e.g. serde.with.
+	PathAttributes []string
+	// Additional macros and attributes that read files at compile time, e.g. include_dir!=dir.
+	CompileDataRules []string
+	// Glob patterns of trybuild UI tests run by tests in this package or its parents, relative to
+	// the repo root. These files don't get targets of their own.
+	TrybuildPatterns []string
+	// Tags and target_compatible_with constraints for targets that enable nightly features.
+	NightlyTags                 []string
+	NightlyTargetCompatibleWith []string
+	// Targets providing native libraries, by library name.
+	NativeLibs map[string]label.Label
+	// Which Rust proto targets to generate for proto_library targets; see rust_proto_rules.
+	ProtoRules string
+	// The kind of new targets for Python extension modules.
+	PythonExtensionKind string
+	// The target attribute of new rust_wasm_bindgen targets.
+	WasmBindgenTarget string
 }
 
 func (cfg *rustConfig) Clone() *rustConfig {
@@ -101,6 +192,15 @@ func (cfg *rustConfig) Clone() *rustConfig {
 	}
 	// Don't copy IgnoredImports - make rust_ignore_import local to each BUILD file
 	copy.IgnoredImports = make(map[string]bool)
+	copy.PathAttributes = append([]string{}, cfg.PathAttributes...)
+	copy.CompileDataRules = append([]string{}, cfg.CompileDataRules...)
+	copy.TrybuildPatterns = append([]string{}, cfg.TrybuildPatterns...)
+	copy.NightlyTags = append([]string{}, cfg.NightlyTags...)
+	copy.NightlyTargetCompatibleWith = append([]string{}, cfg.NightlyTargetCompatibleWith...)
+	copy.NativeLibs = make(map[string]label.Label)
+	for k, v := range cfg.NativeLibs {
+		copy.NativeLibs[k] = v
+	}
 	return &copy
 }
 
@@ -113,12 +213,15 @@ type scopedCrateSet struct {
 type rustLang struct {
 	Parser       *Parser
 	AllCrateSets []scopedCrateSet
+	// The files imported by the protos of each Rust proto target, recorded when it is indexed
+	ProtoImports map[label.Label][]string
 }
 
 func NewLanguage() language.Language {
 	return &rustLang{
 		Parser:       NewParser(),
 		AllCrateSets: []scopedCrateSet{},
+		ProtoImports: map[label.Label][]string{},
 	}
 }
 
@@ -127,14 +230,17 @@ func (*rustLang) Name() string { return langName }
 var (
 	commonDefs []string = []string{"rust_library", "rust_binary", "rust_test",
 		"rust_proc_macro", "rust_shared_library", "rust_static_library"}
+	docTestDefs    []string = []string{"rust_doc_test"}
 	protoDefs      []string = []string{"rust_proto_library", "rust_grpc_library"}
 	prostDefs      []string = []string{"rust_prost_library"}
+	cxxDefs        []string = []string{"rust_cxx_bridge"}
+	wasmDefs       []string = []string{"rust_wasm_bindgen"}
 	cargoDefs      []string = []string{"cargo_build_script"}
 	cargoLintsDefs []string = []string{"extract_cargo_lints"}
 )
 
 var resolvableDefs = append(
-	commonDefs,
+	append(commonDefs, docTestDefs...),
 	cargoDefs...,
 )
 
@@ -149,6 +255,13 @@ func (*rustLang) Kinds() map[string]rule.KindInfo {
 		}
 	}
 
+	for _, docTestDef := range docTestDefs {
+		kinds[docTestDef] = rule.KindInfo{
+			MergeableAttrs: map[string]bool{"deps": true, "proc_macro_deps": true},
+			ResolveAttrs:   map[string]bool{"deps": true, "proc_macro_deps": true},
+		}
+	}
+
 	for _, protoDef := range protoDefs {
 		kinds[protoDef] = rule.KindInfo{
 			MergeableAttrs: map[string]bool{},
@@ -163,6 +276,21 @@ func (*rustLang) Kinds() map[string]rule.KindInfo {
 		}
 	}
 
+	for _, cxxDef := range cxxDefs {
+		kinds[cxxDef] = rule.KindInfo{
+			NonEmptyAttrs:  map[string]bool{"src": true},
+			MergeableAttrs: map[string]bool{"src": true, "deps": true},
+			ResolveAttrs:   map[string]bool{"deps": true},
+		}
+	}
+
+	for _, wasmDef := range wasmDefs {
+		kinds[wasmDef] = rule.KindInfo{
+			MergeableAttrs: map[string]bool{},
+			ResolveAttrs:   map[string]bool{},
+		}
+	}
+
 	for _, cargoDef := range cargoDefs {
 		kinds[cargoDef] = rule.KindInfo{
 			NonEmptyAttrs:  map[string]bool{"srcs": true},
@@ -185,7 +313,7 @@ func (*rustLang) Loads() []rule.LoadInfo {
 	return []rule.LoadInfo{
 		{
 			Name:    "@rules_rust//rust:defs.bzl",
-			Symbols: commonDefs,
+			Symbols: append(commonDefs, docTestDefs...),
 		},
 		{
 			Name:    "@rules_rust//proto:proto.bzl",
@@ -195,6 +323,14 @@ func (*rustLang) Loads() []rule.LoadInfo {
 			Name:    "@rules_rust_prost//:defs.bzl",
 			Symbols: prostDefs,
 		},
+		{
+			Name:    "@cxx.rs//tools/bazel:rust_cxx_bridge.bzl",
+			Symbols: cxxDefs,
+		},
+		{
+			Name:    "@rules_rust_wasm_bindgen//:defs.bzl",
+			Symbols: wasmDefs,
+		},
 		{
 			Name:    "@rules_rust//cargo:defs.bzl",
 			Symbols: append(cargoDefs, cargoLintsDefs...),
@@ -215,7 +351,10 @@ func (*rustLang) KnownDirectives() []string {
 	return []string{modeDirective, lockfileDirective, cargoLockfileDirective,
 		cratesPrefixDirective, procMacroOverrideDirective, allowUnusedCrateDirective,
 		rustFeatureDirective, defaultFeaturesDirective, defaultEditionDirective,
-		srcsGlobDirective, ignoreImportDirective, extractCargoLintsDirective}
+		srcsGlobDirective, ignoreImportDirective, extractCargoLintsDirective,
+		unresolvedHeuristicImportsDirective, pathAttributeDirective, compileDataDirective,
+		nightlyTagDirective, nightlyTargetCompatibleWithDirective, nativeLibDirective,
+		protoRulesDirective, pythonExtensionKindDirective, wasmBindgenTargetDirective}
 }
 
 func (l *rustLang) GetConfig(c *config.Config) *rustConfig {
@@ -253,6 +392,12 @@ func (l *rustLang) Configure(c *config.Config, rel string, from *rule.File) {
 			SrcsGlob:           false,
 			IgnoredImports:     make(map[string]bool),
 			ExtractCargoLints:  false,
+			NativeLibs:         make(map[string]label.Label),
+			ProtoRules:         protoRulesNone,
+
+			UnresolvedHeuristicImports: unresolvedWarn,
+			PythonExtensionKind:        "rust_shared_library",
+			WasmBindgenTarget:          "bundler",
 		}
 	} else {
 		// NOTE(will): important to clone so that we don't leak state across directories
@@ -328,6 +473,64 @@ func (l *rustLang) Configure(c *config.Config, rel string, from *rule.File) {
 						directive.Key, directive.Key)
 				}
 				cfg.ExtractCargoLints = value
+			} else if directive.Key == unresolvedHeuristicImportsDirective {
+				validValues := []string{unresolvedError, unresolvedWarn, unresolvedIgnore}
+				if !SliceContains(validValues, directive.Value) {
+					l.Log(c, logFatal, from, "bad %s: %s, valid options are %v", directive.Key,
+						directive.Value, validValues)
+				}
+				cfg.UnresolvedHeuristicImports = directive.Value
+			} else if directive.Key == pathAttributeDirective {
+				if attr, key, ok := strings.Cut(directive.Value, "."); !ok || attr == "" || key == "" {
+					l.Log(c, logFatal, from, "bad %s: %s, should be gazelle:%s <attribute>.<key>",
+						directive.Key, directive.Value, directive.Key)
+				}
+				cfg.PathAttributes = append(cfg.PathAttributes, directive.Value)
+			} else if directive.Key == compileDataDirective {
+				if target, kind, ok := strings.Cut(directive.Value, "="); !ok || target == "" || kind == "" {
+					l.Log(c, logFatal, from, "bad %s: %s, should be gazelle:%s <target>=<kind>",
+						directive.Key, directive.Value, directive.Key)
+				}
+				cfg.CompileDataRules = append(cfg.CompileDataRules, directive.Value)
+			} else if directive.Key == nightlyTagDirective {
+				cfg.NightlyTags = append(cfg.NightlyTags, directive.Value)
+			} else if directive.Key == nightlyTargetCompatibleWithDirective {
+				if _, err := label.Parse(directive.Value); err != nil {
+					l.Log(c, logFatal, from, "bad %s: %s, should be gazelle:%s <label>",
+						directive.Key, directive.Value, directive.Key)
+				}
+				cfg.NightlyTargetCompatibleWith = append(cfg.NightlyTargetCompatibleWith, directive.Value)
+			} else if directive.Key == nativeLibDirective {
+				split := strings.Fields(directive.Value)
+				if len(split) != 2 {
+					l.Log(c, logFatal, from, "bad %s: %s, should be gazelle:%s <library name> <label>",
+						directive.Key, directive.Value, directive.Key)
+				}
+				nativeLabel, err := label.Parse(split[1])
+				if err != nil {
+					l.Log(c, logFatal, from, "bad %s: %s, should be gazelle:%s <library name> <label>",
+						directive.Key, directive.Value, directive.Key)
+				}
+				cfg.NativeLibs[split[0]] = nativeLabel.Abs("", rel)
+			} else if directive.Key == protoRulesDirective {
+				validValues := []string{protoRulesNone, protoRulesProst, protoRulesRulesRust}
+				if !SliceContains(validValues, directive.Value) {
+					l.Log(c, logFatal, from, "bad %s: %s, valid options are %v", directive.Key,
+						directive.Value, validValues)
+				}
+				cfg.ProtoRules = directive.Value
+			} else if directive.Key == pythonExtensionKindDirective {
+				if !SliceContains(commonDefs, directive.Value) {
+					l.Log(c, logFatal, from, "bad %s: %s, valid options are %v", directive.Key,
+						directive.Value, commonDefs)
+				}
+				cfg.PythonExtensionKind = directive.Value
+			} else if directive.Key == wasmBindgenTargetDirective {
+				if !SliceContains(wasmBindgenTargets, directive.Value) {
+					l.Log(c, logFatal, from, "bad %s: %s, valid options are %v", directive.Key,
+						directive.Value, wasmBindgenTargets)
+				}
+				cfg.WasmBindgenTarget = directive.Value
 			}
 		}
 	}
@@ -335,6 +538,10 @@ func (l *rustLang) Configure(c *config.Config, rel string, from *rule.File) {
 	for k, v := range c.KindMap {
 		cfg.KindMapInverse[v.KindName] = k
 	}
+
+	if cfg.Mode == modePureBazel {
+		cfg.TrybuildPatterns = append(cfg.TrybuildPatterns, l.findTrybuildPatterns(c, rel)...)
+	}
 }
 
 func (l *rustLang) DoneResolving(c *config.Config) {
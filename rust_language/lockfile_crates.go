@@ -9,7 +9,8 @@ import (
 
 // For cargo lockfiles, we guess whether each crate is a proc_macro by checking its dependencies for
 // proc-macro or proc-macro2. Sometimes this is wrong. For this purpose, we have this mapping of
-// known overrides. The user can also specify additional overrides with a directive.
+// known overrides. The user can also specify additional overrides with a directive. Guesses are also
+// corrected in resolve.go based on how each crate is used.
 var procMacroOverrides map[string]bool = map[string]bool{
 	"syn": false,
 }
@@ -23,6 +24,9 @@ type LockfileCrates struct {
 	DependenciesPerCrate map[string](map[string]string)
 	// track which crates have been used so that we can report unused crates
 	UsedCrates map[string]bool
+	// whether proc_macro classifications were guessed rather than read from the lockfile, which is
+	// the case for cargo lockfiles
+	ProcMacroGuessed bool
 }
 
 func EmptyLockfileCrates() *LockfileCrates {
@@ -36,6 +40,7 @@ func EmptyLockfileCrates() *LockfileCrates {
 
 func (l *rustLang) NewLockfileCrates(c *config.Config, lockfilePath string, cargo bool) *LockfileCrates {
 	lockfileCrates := EmptyLockfileCrates()
+	lockfileCrates.ProcMacroGuessed = cargo
 
 	var request *pb.LockfileCratesRequest
 	if cargo {
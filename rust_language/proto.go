@@ -0,0 +1,216 @@
+package rust_language
+
+import (
+	"os"
+	"path"
+	"path/filepath"
+	"regexp"
+	"strings"
+
+	"github.com/bazelbuild/bazel-gazelle/config"
+	"github.com/bazelbuild/bazel-gazelle/label"
+	"github.com/bazelbuild/bazel-gazelle/language"
+	"github.com/bazelbuild/bazel-gazelle/resolve"
+	"github.com/bazelbuild/bazel-gazelle/rule"
+)
+
+var (
+	protoCommentRegexp = regexp.MustCompile(`//.*`)
+	protoPackageRegexp = regexp.MustCompile(`(?m)^\s*package\s+([\w.]+)\s*;`)
+	protoImportRegexp  = regexp.MustCompile(`(?m)^\s*import\s+(?:public\s+|weak\s+)?"([^"]+)"\s*;`)
+	protoServiceRegexp = regexp.MustCompile(`(?m)^\s*service\s+\w+\s*\{`)
+)
+
+// What we need to know about a .proto file. This only understands the common layout of one
+// statement per line, which is enough for generating and resolving rules.
+type protoFile struct {
+	// e.g. my.pkg.v1, or empty if there is no package statement
+	Package string
+	// imported files, e.g. my/pkg/v1/types.proto
+	Imports     []string
+	HasServices bool
+}
+
+func parseProtoFile(path string) (*protoFile, error) {
+	contents, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	contents = protoCommentRegexp.ReplaceAll(contents, nil)
+
+	file := &protoFile{
+		HasServices: protoServiceRegexp.Match(contents),
+	}
+	if match := protoPackageRegexp.FindSubmatch(contents); match != nil {
+		file.Package = string(match[1])
+	}
+	for _, match := range protoImportRegexp.FindAllSubmatch(contents, -1) {
+		file.Imports = append(file.Imports, string(match[1]))
+	}
+	return file, nil
+}
+
+// parseProtoLibrary parses the sources of a proto_library in the package rel, keyed by their paths
+// relative to the repository root. Sources that can't be read are skipped.
+func parseProtoLibrary(c *config.Config, rel string, protoRule *rule.Rule) map[string]*protoFile {
+	files := map[string]*protoFile{}
+	for _, src := range protoRule.AttrStrings("srcs") {
+		if file, err := parseProtoFile(filepath.Join(c.RepoRoot, rel, src)); err == nil {
+			files[path.Join(rel, src)] = file
+		}
+	}
+	return files
+}
+
+// protoSpecs returns import specs for the protobuf packages declared by the sources of the given
+// proto_library targets, so that code including the generated code by package (e.g.
+// tonic::include_proto!("my.pkg.v1")) can be resolved to the rule generating it, and for the
+// sources themselves, so that the protos importing them can be resolved. The files that the sources
+// import are recorded for the rule r. The proto_library targets are read from the build file of the
+// package that declares them, which is f for targets in the same package.
+// NOTE: proto_library targets in other packages are read from disk, so targets that are generated
+// in the same run can only be read in the same package.
+func (l *rustLang) protoSpecs(c *config.Config, r *rule.Rule, f *rule.File,
+	protoLabels []string) []resolve.ImportSpec {
+	if f == nil {
+		return nil
+	}
+
+	var specs []resolve.ImportSpec
+	var imports []string
+	for _, protoLabel := range protoLabels {
+		lbl, err := label.Parse(protoLabel)
+		if err != nil || lbl.Repo != "" {
+			continue
+		}
+		protoPkg := lbl.Pkg
+		if lbl.Relative {
+			protoPkg = f.Pkg
+		}
+		protoBuildFile := f
+		if protoPkg != f.Pkg {
+			if protoBuildFile = loadBuildFile(c, protoPkg); protoBuildFile == nil {
+				continue
+			}
+		}
+		for _, protoRule := range protoBuildFile.Rules {
+			if protoRule.Kind() != "proto_library" || protoRule.Name() != lbl.Name {
+				continue
+			}
+			for src, file := range parseProtoLibrary(c, protoPkg, protoRule) {
+				specs = append(specs, resolve.ImportSpec{
+					Lang: protoFileLangName,
+					Imp:  src,
+				})
+				if file.Package != "" {
+					specs = append(specs, resolve.ImportSpec{
+						Lang: protoPackageLangName,
+						Imp:  file.Package,
+					})
+				}
+				imports = append(imports, file.Imports...)
+			}
+		}
+	}
+	l.ProtoImports[label.New(c.RepoName, f.Pkg, r.Name())] = imports
+	return specs
+}
+
+// loadBuildFile reads the build file of the package rel, or returns nil if there is none.
+func loadBuildFile(c *config.Config, rel string) *rule.File {
+	for _, buildFileName := range c.ValidBuildFileNames {
+		if f, err := rule.LoadFile(filepath.Join(c.RepoRoot, rel, buildFileName), rel); err == nil {
+			return f
+		}
+	}
+	return nil
+}
+
+// generateProtoRules generates a Rust proto target for each proto_library in the package that
+// doesn't have one yet, if enabled with gazelle:rust_proto_rules.
+func (l *rustLang) generateProtoRules(args language.GenerateArgs, result *language.GenerateResult) {
+	cfg := l.GetConfig(args.Config)
+	if cfg.ProtoRules == protoRulesNone {
+		return
+	}
+
+	existingRuleNames := map[string]bool{}
+	// proto_library targets that already have a Rust proto target, by name
+	wrappedProtos := map[string]bool{}
+	protoRules := []*rule.Rule{}
+
+	if args.File != nil {
+		for _, r := range args.File.Rules {
+			existingRuleNames[r.Name()] = true
+		}
+	}
+	for _, r := range result.Gen {
+		existingRuleNames[r.Name()] = true
+	}
+	// proto_library targets may be generated by the proto extension in the same run
+	allRules := args.OtherGen
+	if args.File != nil {
+		allRules = append(append([]*rule.Rule{}, args.File.Rules...), allRules...)
+	}
+	for _, r := range allRules {
+		switch r.Kind() {
+		case "proto_library":
+			protoRules = append(protoRules, r)
+		case "rust_prost_library":
+			wrappedProtos[protoRuleName(args.Rel, r.AttrString("proto"))] = true
+		case "rust_proto_library", "rust_grpc_library":
+			for _, dep := range r.AttrStrings("deps") {
+				wrappedProtos[protoRuleName(args.Rel, dep)] = true
+			}
+		}
+	}
+
+	for _, protoRule := range protoRules {
+		if wrappedProtos[protoRule.Name()] {
+			continue
+		}
+		wrappedProtos[protoRule.Name()] = true
+
+		hasServices := false
+		for _, file := range parseProtoLibrary(args.Config, args.Rel, protoRule) {
+			hasServices = hasServices || file.HasServices
+		}
+
+		// e.g. foo_proto -> foo_rust_proto
+		baseName := strings.TrimSuffix(protoRule.Name(), "_proto")
+		ruleName := freshRuleName(baseName+"_rust_proto", existingRuleNames)
+		if ruleName == nil {
+			l.Log(args.Config, logWarn, args.File, "could not find a suitable rule name for %s, all candidates already taken", protoRule.Name())
+			continue
+		}
+		existingRuleNames[*ruleName] = true
+
+		var newRule *rule.Rule
+		if cfg.ProtoRules == protoRulesProst {
+			// the crate is named after the proto_library, e.g. foo_proto
+			newRule = rule.NewRule("rust_prost_library", *ruleName)
+			newRule.SetAttr("proto", ":"+protoRule.Name())
+		} else {
+			kind := "rust_proto_library"
+			if hasServices {
+				kind = "rust_grpc_library"
+			}
+			// the crate is named after this rule, e.g. foo_rust_proto
+			newRule = rule.NewRule(kind, *ruleName)
+			newRule.SetAttr("deps", []string{":" + protoRule.Name()})
+		}
+
+		result.Gen = append(result.Gen, newRule)
+		result.Imports = append(result.Imports, RuleData{rule: newRule})
+	}
+}
+
+// protoRuleName returns the name of a proto_library referred to by a label, if it is in the package
+// rel, or else an empty string.
+func protoRuleName(rel string, protoLabel string) string {
+	lbl, err := label.Parse(protoLabel)
+	if err != nil || lbl.Repo != "" || (lbl.Pkg != rel && !lbl.Relative) {
+		return ""
+	}
+	return lbl.Name
+}
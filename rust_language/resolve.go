@@ -9,6 +9,8 @@ import (
 	"github.com/bazelbuild/bazel-gazelle/repo"
 	"github.com/bazelbuild/bazel-gazelle/resolve"
 	"github.com/bazelbuild/bazel-gazelle/rule"
+
+	pb "github.com/calsign/gazelle_rust/proto"
 )
 
 func getCrateName(r *rule.Rule) string {
@@ -41,6 +43,7 @@ func (l *rustLang) Imports(c *config.Config, r *rule.Rule,
 			Lang: l.Name(),
 			Imp:  r.Name(),
 		})
+		specs = append(specs, l.protoSpecs(c, r, f, r.AttrStrings("deps"))...)
 	case "rust_prost_library":
 		// rules_rust_prost derives the crate name from the proto_library target name
 		protoAttr := r.AttrString("proto")
@@ -53,6 +56,7 @@ func (l *rustLang) Imports(c *config.Config, r *rule.Rule,
 					Imp:  crateName,
 				})
 			}
+			specs = append(specs, l.protoSpecs(c, r, f, []string{protoAttr})...)
 		}
 	}
 
@@ -88,17 +92,49 @@ func (l *rustLang) Resolve(c *config.Config, ix *resolve.RuleIndex,
 		}
 
 		var crateName string
-		if ruleData.testedCrate != nil {
+		if r.Kind() == "rust_doc_test" {
+			// doc tests are linked against the documented crate
+			crateName = getCrateName(ruleData.testedCrate)
+		} else if ruleData.testedCrate != nil {
 			// test crates have to depend on the tested crate to be able to import them directly
 			crateName = ""
 		} else {
 			crateName = getCrateName(r)
 		}
 
+		// how each crate is used across all of the sources for this rule
+		crateUsages := map[string]map[pb.UsageContext]bool{}
+		for _, response := range ruleData.responses {
+			for _, usage := range response.GetCrateUsages() {
+				if _, ok := crateUsages[usage.CrateName]; !ok {
+					crateUsages[usage.CrateName] = map[pb.UsageContext]bool{}
+				}
+				for _, context := range usage.Contexts {
+					crateUsages[usage.CrateName][context] = true
+				}
+			}
+		}
+
+		// the imports of the documented crate, whose deps doc tests are also linked against
+		docTestCrateImports := map[string]bool{}
+		if r.Kind() == "rust_doc_test" {
+			for _, response := range ruleData.responses {
+				for _, imp := range response.GetImports() {
+					docTestCrateImports[imp] = true
+				}
+			}
+		}
+
 		for _, response := range ruleData.responses {
 			var imports []string
 
-			if r.Kind() == "rust_test" {
+			if r.Kind() == "rust_doc_test" {
+				for _, imp := range response.GetDocTestImports() {
+					if !docTestCrateImports[imp] {
+						imports = append(imports, imp)
+					}
+				}
+			} else if r.Kind() == "rust_test" {
 				if ruleData.testedCrate == nil {
 					// this is a standalone test
 					imports = append(response.GetImports(), response.GetTestImports()...)
@@ -110,6 +146,11 @@ func (l *rustLang) Resolve(c *config.Config, ix *resolve.RuleIndex,
 				imports = response.GetImports()
 			}
 
+			heuristicImports := map[string]bool{}
+			for _, imp := range response.GetHeuristicImports() {
+				heuristicImports[imp] = true
+			}
+
 			for _, imp := range imports {
 				// Skip imports that are explicitly ignored
 				if cfg.IgnoredImports[imp] {
@@ -134,17 +175,22 @@ func (l *rustLang) Resolve(c *config.Config, ix *resolve.RuleIndex,
 
 				is_proc_macro := false
 
-				resolvedLabel, found := l.resolveCrate(cfg, c, ix, l.Name(), actualCrate, ruleData.parentCrateName, from)
+				resolvedLabel, found, kind := l.resolveCrate(cfg, c, ix, l.Name(), actualCrate, ruleData.parentCrateName, from)
 				if resolvedLabel != nil {
 					is_proc_macro = false
 				}
 				if !found {
-					resolvedLabel, found = l.resolveCrate(cfg, c, ix, procMacroLangName, actualCrate, ruleData.parentCrateName, from)
+					resolvedLabel, found, kind = l.resolveCrate(cfg, c, ix, procMacroLangName, actualCrate, ruleData.parentCrateName, from)
 					if resolvedLabel != nil {
 						is_proc_macro = true
 					}
 				}
 
+				if resolvedLabel != nil {
+					// fall back to how the crate is used in the source
+					is_proc_macro = inferProcMacro(kind, is_proc_macro, crateUsages[imp])
+				}
+
 				if proc_macro, ok := cfg.ProcMacroOverrides[actualCrate]; ok {
 					// user-defined override
 					// NOTE: well-known overrides are handled in lockfile_crates.go
@@ -163,19 +209,50 @@ func (l *rustLang) Resolve(c *config.Config, ix *resolve.RuleIndex,
 							aliases[*resolvedLabel] = imp
 						}
 					}
-				} else {
+				} else if !heuristicImports[imp] {
 					l.Log(c, logErr, from, "no match for %s\n", imp)
+				} else if cfg.UnresolvedHeuristicImports == unresolvedError {
+					l.Log(c, logErr, from, "no match for %s (heuristic)\n", imp)
+				} else if cfg.UnresolvedHeuristicImports == unresolvedWarn {
+					l.Log(c, logWarn, from, "no match for %s (heuristic)\n", imp)
 				}
 			}
 		}
 
+		// test and doc test crates are already linked against the native libraries and generated
+		// code of the crate
+		if r.Kind() != "rust_doc_test" && ruleData.testedCrate == nil {
+			for _, response := range ruleData.responses {
+				for _, nativeLib := range response.GetNativeLibs() {
+					if nativeLabel, ok := cfg.NativeLibs[nativeLib.Name]; ok {
+						deps[nativeLabel] = true
+					}
+				}
+				for _, protoPackage := range response.GetProtoPackages() {
+					if protoLabel := l.resolveProtoPackage(c, ix, protoPackage, from); protoLabel != nil {
+						deps[*protoLabel] = true
+					}
+				}
+			}
+		}
+
+		// generated proto code refers to the code generated for the protos that it imports
+		for _, protoLabel := range l.protoImportDeps(c, ix, deps, from) {
+			deps[protoLabel] = true
+		}
+
 		if ruleData.buildScript != nil {
 			deps[*ruleData.buildScript] = true
 		}
+		for _, cxxBridge := range ruleData.cxxBridges {
+			deps[cxxBridge] = true
+		}
 
 		maybeSetAttrStrings(r, "deps", finalizeDeps(deps, from))
 		maybeSetAttrStrings(r, "proc_macro_deps", finalizeDeps(procMacroDeps, from))
 		maybeSetAliases(r, aliases, from)
+	} else if r.Kind() == "rust_cxx_bridge" {
+		l.resolveCxxBridge(c, r, ruleData.(RuleData), from)
 	}
 }
 
@@ -221,27 +298,56 @@ func (l *rustLang) resolveCrateVersion(cfg *rustConfig, c *config.Config,
 	return version
 }
 
+// crateKind is how much is known about whether a resolved crate is a proc_macro crate.
+type crateKind int
+
+const (
+	// the crate is known to be (or not be) a proc_macro crate
+	crateKindKnown crateKind = iota
+	// the crate was guessed to be (or not be) a proc_macro crate, e.g. from a cargo lockfile
+	crateKindGuessed
+)
+
+// inferProcMacro decides whether a crate is a proc_macro crate, taking into account how the crate
+// is used when its kind is not known for sure.
+func inferProcMacro(kind crateKind, isProcMacro bool, usages map[pb.UsageContext]bool) bool {
+	switch kind {
+	case crateKindGuessed:
+		// proc_macro crates can only export macros, so anything used as an ordinary path must be
+		// a regular crate
+		// NOTE: The reverse does not hold, since regular crates often re-export derive macros and
+		// attributes (e.g. serde::Serialize, tokio::main).
+		return isProcMacro && !usages[pb.UsageContext_PATH]
+	default:
+		return isProcMacro
+	}
+}
+
+// resolveCrate finds the label for a crate. Also returns whether the crate was found and how much
+// is known about whether it is a proc_macro crate.
 func (l *rustLang) resolveCrate(cfg *rustConfig, c *config.Config, ix *resolve.RuleIndex,
-	lang string, imp string, parentCrateName string, from label.Label) (*label.Label, bool) {
+	lang string, imp string, parentCrateName string, from label.Label) (*label.Label, bool, crateKind) {
 	spec := resolve.ImportSpec{
 		Lang: lang,
 		Imp:  imp,
 	}
 
 	if Builtins[spec.Imp] {
-		return nil, true
+		return nil, true, crateKindKnown
 	} else if override, ok := resolve.FindRuleWithOverride(c, spec, l.Name()); ok {
-		return &override, true
+		// the user chose between rust and rust_proc_macro, so the crate isn't reclassified based on
+		// how it is used; regular crates often re-export derive macros and attributes
+		return &override, true, crateKindKnown
 	} else if candidates := ix.FindRulesByImportWithConfig(c, spec, l.Name()); len(candidates) >= 1 {
 		if len(candidates) == 1 {
-			return &candidates[0].Label, true
+			return &candidates[0].Label, true, crateKindKnown
 		} else {
 			candidateLabels := []string{}
 			for _, candidate := range candidates {
 				candidateLabels = append(candidateLabels, candidate.Label.String())
 			}
 			l.Log(c, logErr, from, "multiple matches found for %s: [%s]\n", spec.Imp, strings.Join(candidateLabels, ", "))
-			return nil, true
+			return nil, true, crateKindKnown
 		}
 	} else if crate, ok := cfg.LockfileCrates.Crates[spec]; ok {
 		var crateLabel label.Label
@@ -260,12 +366,99 @@ func (l *rustLang) resolveCrate(cfg *rustConfig, c *config.Config, ix *resolve.R
 		// track this crate as used
 		cfg.LockfileCrates.UsedCrates[crate] = true
 
-		return &crateLabel, true
+		if cfg.LockfileCrates.ProcMacroGuessed {
+			return &crateLabel, true, crateKindGuessed
+		}
+		return &crateLabel, true, crateKindKnown
 	} else if override, ok := Provided[lang][spec.Imp]; ok {
-		return &override, true
+		return &override, true, crateKindKnown
 	} else {
-		return nil, false
+		return nil, false, crateKindKnown
+	}
+}
+
+// resolveProtoPackage finds the rust_prost_library or rust_proto_library generating the code for a
+// protobuf package. Packages that aren't found are assumed to be generated in some other way, e.g.
+// by a build script.
+func (l *rustLang) resolveProtoPackage(c *config.Config, ix *resolve.RuleIndex,
+	protoPackage string, from label.Label) *label.Label {
+	spec := resolve.ImportSpec{
+		Lang: protoPackageLangName,
+		Imp:  protoPackage,
+	}
+
+	if override, ok := resolve.FindRuleWithOverride(c, spec, l.Name()); ok {
+		return &override
+	}
+	candidates := ix.FindRulesByImportWithConfig(c, spec, l.Name())
+	if len(candidates) > 1 {
+		candidateLabels := []string{}
+		for _, candidate := range candidates {
+			candidateLabels = append(candidateLabels, candidate.Label.String())
+		}
+		l.Log(c, logErr, from, "multiple matches found for proto package %s: [%s]\n", protoPackage, strings.Join(candidateLabels, ", "))
+		return nil
+	} else if len(candidates) == 1 {
+		return &candidates[0].Label
+	}
+	return nil
+}
+
+// protoImportDeps returns the Rust proto targets generating the protos imported (transitively) by
+// the protos of the Rust proto targets in deps.
+func (l *rustLang) protoImportDeps(c *config.Config, ix *resolve.RuleIndex,
+	deps map[label.Label]bool, from label.Label) []label.Label {
+	var result []label.Label
+	seen := map[label.Label]bool{from: true}
+	queue := []label.Label{}
+	for dep := range deps {
+		seen[dep] = true
+		queue = append(queue, dep)
+	}
+	for len(queue) > 0 {
+		dep := queue[0]
+		queue = queue[1:]
+		for _, imp := range l.ProtoImports[dep] {
+			spec := resolve.ImportSpec{
+				Lang: protoFileLangName,
+				Imp:  imp,
+			}
+			for _, candidate := range ix.FindRulesByImportWithConfig(c, spec, l.Name()) {
+				if !seen[candidate.Label] {
+					seen[candidate.Label] = true
+					result = append(result, candidate.Label)
+					queue = append(queue, candidate.Label)
+				}
+			}
+		}
+	}
+	return result
+}
+
+// resolveCxxBridge sets the deps of a cxx bridge to the targets that provide the C++ headers that it
+// includes. There's no way to find these targets, so they are configured with
+// gazelle:resolve rust rust_cxx_header <header> <label>.
+func (l *rustLang) resolveCxxBridge(c *config.Config, r *rule.Rule, ruleData RuleData,
+	from label.Label) {
+	deps := map[label.Label]bool{}
+	for _, response := range ruleData.responses {
+		for _, header := range response.GetCxxIncludes() {
+			if header == "rust/cxx.h" {
+				// provided by cxx itself
+				continue
+			}
+			spec := resolve.ImportSpec{
+				Lang: cxxHeaderLangName,
+				Imp:  header,
+			}
+			if override, ok := resolve.FindRuleWithOverride(c, spec, l.Name()); ok {
+				deps[override] = true
+			} else {
+				l.Log(c, logWarn, from, "no match for C++ header %s\n", header)
+			}
+		}
 	}
+	maybeSetAttrStrings(r, "deps", finalizeDeps(deps, from))
 }
 
 func finalizeDeps(deps map[label.Label]bool, from label.Label) []string {
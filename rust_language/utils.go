@@ -3,6 +3,8 @@ package rust_language
 import (
 	"fmt"
 	"log"
+	"path"
+	"strings"
 
 	"github.com/bazelbuild/bazel-gazelle/config"
 	"github.com/bazelbuild/bazel-gazelle/label"
@@ -48,6 +50,33 @@ func (l *rustLang) Log(c *config.Config, level logLevel, from interface{}, msg s
 	}
 }
 
+// matchGlob reports whether a slash-separated path matches a glob pattern. Besides the syntax of
+// path.Match, a ** segment matches any number of path segments.
+func matchGlob(pattern string, name string) bool {
+	return matchGlobSegments(strings.Split(path.Clean(pattern), "/"), strings.Split(name, "/"))
+}
+
+func matchGlobSegments(pattern []string, name []string) bool {
+	if len(pattern) == 0 {
+		return len(name) == 0
+	}
+	if pattern[0] == "**" {
+		for i := 0; i <= len(name); i++ {
+			if matchGlobSegments(pattern[1:], name[i:]) {
+				return true
+			}
+		}
+		return false
+	}
+	if len(name) == 0 {
+		return false
+	}
+	if ok, err := path.Match(pattern[0], name[0]); err != nil || !ok {
+		return false
+	}
+	return matchGlobSegments(pattern[1:], name[1:])
+}
+
 func SliceContains(slice []string, value string) bool {
 	for _, item := range slice {
 		if item == value {
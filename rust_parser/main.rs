@@ -8,9 +8,9 @@ use clap::Parser;
 use prost::Message;
 
 use messages_proto::{
-    CargoCrateInfo, CargoTomlRequest, CargoTomlResponse, DependencyAlias, Hints,
-    LockfileCratesRequest, LockfileCratesResponse, Request, RustImportsRequest,
-    RustImportsResponse, lockfile_crates_request, request,
+    CargoCrateInfo, CargoTomlRequest, CargoTomlResponse, CrateUsage, DependencyAlias, Hints,
+    LockfileCratesRequest, LockfileCratesResponse, NativeLib, Request, RustImportsRequest,
+    RustImportsResponse, UsageContext, lockfile_crates_request, request,
 };
 
 #[derive(clap::Parser)]
@@ -22,11 +22,21 @@ enum Args {
 fn handle_rust_imports_request(
     request: RustImportsRequest,
 ) -> Result<RustImportsResponse, Box<dyn Error>> {
-    let rust_imports = gazelle_rust_parser::parse_imports(
-        PathBuf::from(request.absolute_path),
-        PathBuf::from(request.relative_path),
-        &request.enabled_features,
-    );
+    let rust_imports = parse_edition(&request.edition).and_then(|edition| {
+        let options = gazelle_rust_parser::ParseOptions {
+            enabled_features: request.enabled_features,
+            edition,
+            path_attributes: request.path_attributes,
+            compile_data_rules: request.compile_data_rules,
+            enabled_cfgs: request.enabled_cfgs,
+            build_script: request.build_script,
+        };
+        gazelle_rust_parser::parse_imports(
+            PathBuf::from(request.absolute_path),
+            PathBuf::from(request.relative_path),
+            &options,
+        )
+    });
 
     let mut response = RustImportsResponse::default();
     match rust_imports {
@@ -35,6 +45,22 @@ fn handle_rust_imports_request(
                 has_main: rust_imports.hints.has_main,
                 has_test: rust_imports.hints.has_test,
                 has_proc_macro: rust_imports.hints.has_proc_macro,
+                has_proc_macro_derive: rust_imports.hints.has_proc_macro_derive,
+                has_c_exports: rust_imports.hints.has_c_exports,
+                no_std: rust_imports.hints.no_std,
+                no_main: rust_imports.hints.no_main,
+                crate_types: rust_imports.hints.crate_types,
+                crate_name: rust_imports.hints.crate_name.unwrap_or_default(),
+                has_doc_test: rust_imports.hints.has_doc_test,
+                has_inline_snapshot: rust_imports.hints.has_inline_snapshot,
+                has_file_snapshot: rust_imports.hints.has_file_snapshot,
+                snapshot_dirs: rust_imports.hints.snapshot_dirs,
+                has_bench: rust_imports.hints.has_bench,
+                nightly_features: rust_imports.hints.nightly_features,
+                has_cxx_bridge: rust_imports.hints.has_cxx_bridge,
+                python_module: rust_imports.hints.python_module.unwrap_or_default(),
+                has_pyfunction: rust_imports.hints.has_pyfunction,
+                has_wasm_bindgen_exports: rust_imports.hints.has_wasm_bindgen_exports,
             };
 
             response.success = true;
@@ -43,6 +69,37 @@ fn handle_rust_imports_request(
             response.test_imports = rust_imports.test_imports;
             response.extern_mods = rust_imports.extern_mods;
             response.compile_data = rust_imports.compile_data;
+            response.data = rust_imports.data;
+            response.runfiles = rust_imports.runfiles;
+            response.trybuild_patterns = rust_imports.trybuild_patterns;
+            response.build_script_data = rust_imports.build_script_data;
+            response.build_script_cfgs = rust_imports.build_script_cfgs;
+            response.native_libs = rust_imports
+                .native_libs
+                .into_iter()
+                .map(|lib| NativeLib {
+                    name: lib.name,
+                    kind: lib.kind,
+                })
+                .collect();
+            response.proto_packages = rust_imports.proto_packages;
+            response.cxx_includes = rust_imports.cxx_includes;
+            response.included_srcs = rust_imports.included_srcs;
+            response.exported_macro_imports = rust_imports.exported_macro_imports;
+            response.heuristic_imports = rust_imports.heuristic_imports;
+            response.crate_usages = rust_imports
+                .crate_usages
+                .into_iter()
+                .map(|usage| CrateUsage {
+                    crate_name: usage.crate_name,
+                    contexts: usage
+                        .contexts
+                        .into_iter()
+                        .map(|context| convert_usage_context(context) as i32)
+                        .collect(),
+                })
+                .collect();
+            response.doc_test_imports = rust_imports.doc_test_imports;
         }
         Err(err) => {
             // Don't crash gazelle if we encounter an error, instead bubble it up so that we can
@@ -56,6 +113,25 @@ fn handle_rust_imports_request(
     Ok(response)
 }
 
+fn convert_usage_context(context: gazelle_rust_parser::UsageContext) -> UsageContext {
+    match context {
+        gazelle_rust_parser::UsageContext::Use => UsageContext::Use,
+        gazelle_rust_parser::UsageContext::Path => UsageContext::Path,
+        gazelle_rust_parser::UsageContext::Derive => UsageContext::Derive,
+        gazelle_rust_parser::UsageContext::Attribute => UsageContext::Attribute,
+        gazelle_rust_parser::UsageContext::Macro => UsageContext::Macro,
+    }
+}
+
+/// Parse the edition from a request, using the default edition if it is unset.
+fn parse_edition(edition: &str) -> Result<gazelle_rust_parser::Edition, Box<dyn Error>> {
+    if edition.is_empty() {
+        Ok(gazelle_rust_parser::Edition::default())
+    } else {
+        Ok(edition.parse()?)
+    }
+}
+
 fn handle_lockfile_crates_request(
     request: LockfileCratesRequest,
 ) -> Result<LockfileCratesResponse, Box<dyn Error>> {
@@ -158,7 +234,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     match args {
         Args::OneShot { path } => {
-            let mut rust_imports = gazelle_rust_parser::parse_imports(path, PathBuf::new(), &[])?;
+            let mut rust_imports = gazelle_rust_parser::parse_imports(
+                path,
+                PathBuf::new(),
+                &gazelle_rust_parser::ParseOptions::default(),
+            )?;
             rust_imports.imports.sort();
 
             println!("Imports:");